@@ -0,0 +1,21 @@
+library;
+
+use std::string::String;
+
+pub enum MintError {
+    EditionNotFound: (),
+    MaxSupplyReached: (),
+    NotEnoughTokens: u64,
+    InvalidAsset: (),
+    InvalidAmount: (),
+    OutsideMintingPeriod: String,
+    ProtocolPaused: (),
+}
+
+pub enum SetError {
+    EditionAlreadyExists: (),
+    EditionNotFound: (),
+    InvalidMintWindow: (),
+    LengthMismatch: (),
+    InvalidFeePercentages: (),
+}
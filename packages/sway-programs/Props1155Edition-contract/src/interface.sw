@@ -0,0 +1,61 @@
+library;
+
+use std::string::String;
+use std::vec::Vec;
+use standards::src7::Metadata;
+use libraries::MintQuote;
+
+/// The configuration of a single edition hosted by the contract.
+pub struct Edition {
+    /// The sub identifier of the edition's asset.
+    pub sub_id: SubId,
+    /// The price of a single copy.
+    pub price: u64,
+    /// The maximum number of copies, or `0` for an unlimited supply.
+    pub max_supply: u64,
+    /// The start date for minting, or `0` for no start date.
+    pub start_date: u64,
+    /// The end date for minting, or `0` for no end date.
+    pub end_date: u64,
+}
+
+abi Props1155Edition {
+    #[storage(read, write)]
+    fn constructor(owner: Identity, name: String, symbol: String);
+}
+
+abi MultiEdition {
+    #[storage(read, write)]
+    fn create_edition(sub_id: SubId, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, max_supply: u64, start_date: u64, end_date: u64) -> AssetId;
+
+    #[storage(read, write)]
+    fn set_edition_price(asset: AssetId, price: u64);
+
+    #[storage(read, write)]
+    fn set_edition_dates(asset: AssetId, start: u64, end: u64);
+
+    #[storage(read)]
+    fn edition(asset: AssetId) -> Option<Edition>;
+
+    #[storage(read)]
+    fn editions(offset: u64, limit: u64) -> Vec<AssetId>;
+
+    #[storage(read)]
+    fn total_editions() -> u64;
+}
+
+abi MultiEditionMint {
+    #[payable]
+    #[storage(read, write)]
+    fn mint(recipient: Identity, sub_id: SubId, amount: u64, affiliate: Option<Identity>);
+
+    #[storage(read)]
+    fn quote_mint(recipient: Identity, sub_id: SubId, amount: u64, affiliate: Option<Identity>) -> MintQuote;
+
+    #[storage(read, write)]
+    fn airdrop(recipient: Identity, sub_id: SubId, amount: u64);
+
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64);
+}
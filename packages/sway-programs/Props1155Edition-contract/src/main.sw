@@ -0,0 +1,1484 @@
+contract;
+
+mod errors;
+mod interface;
+
+use errors::{MintError, SetError};
+use interface::{Edition, MultiEdition, MultiEditionMint, Props1155Edition};
+use standards::{src20::SRC20, src5::{SRC5, State}, src7::{Metadata, SRC7},};
+use standards::src20::{SetNameEvent, SetSymbolEvent, SetDecimalsEvent, TotalSupplyEvent};
+use standards::src7::{SetMetadataEvent};
+use sway_libs::{
+    asset::{
+        base::{
+            _total_assets,
+            _total_supply,
+        },
+        metadata::*,
+        supply::{
+            _burn,
+            _mint,
+        },
+    },
+    pausable::{
+        _is_paused,
+        _pause,
+        _unpause,
+        Pausable,
+        require_not_paused,
+    },
+    reentrancy::reentrancy_guard,
+};
+use std::{hash::*, storage::storage_string::*, storage::storage_vec::*, string::String};
+use std::logging::log;
+use std::context::{msg_amount, this_balance};
+use std::auth::msg_sender;
+use std::call_frames::msg_asset_id;
+use std::asset::{transfer};
+use std::block::timestamp;
+
+use libraries::*;
+use libraries::roles::{_grant_role, _has_role, _revoke_role, only_role, FINANCE_ROLE, METADATA_ROLE, MINTER_ROLE, PAUSER_ROLE};
+use libraries::ownership::{_accept_ownership, _renounce_ownership, _transfer_ownership, only_registry};
+use sway_libs::ownership::{_owner, initialize_ownership, only_owner};
+use libraries::sweep::_sweep;
+
+// release
+const FEE_CONTRACT_ID = 0xe63564f83a2b82b97ea3f42d1680eeca825e3596b76da197ea4f6f6595810562;
+
+// debug
+// const FEE_CONTRACT_ID = 0xd65987a6b981810a28559d57e5083d47a10ce269cbf96316554d5b4a1b78485a;
+
+storage {
+    /// The total number of editions that have been minted at least once.
+    ///
+    /// # Additional Information
+    ///
+    /// Each edition is a single asset, so this is the number of assets with a non-zero supply history.
+    total_assets: u64 = 0,
+
+    /// The total number of copies minted for each edition.
+    ///
+    /// # Additional Information
+    ///
+    /// Unlike the 721 contracts, an edition's supply may be greater than one.
+    total_supply: StorageMap<AssetId, u64> = StorageMap {},
+
+    /// The number of copies ever minted for each edition.
+    ///
+    /// # Additional Information
+    ///
+    /// Burning does not decrease this count, so burned copies cannot be minted again past `max_supply`.
+    minted: StorageMap<AssetId, u64> = StorageMap {},
+
+    /// The name shared by all editions.
+    name: StorageString = StorageString {},
+
+    /// The symbol shared by all editions.
+    symbol: StorageString = StorageString {},
+
+    /// The metadata of each edition.
+    metadata: StorageMetadata = StorageMetadata {},
+
+    /// The configuration of each edition, keyed by its asset.
+    editions: StorageMap<AssetId, Edition> = StorageMap {},
+
+    /// The assets of all editions in the order they were created.
+    edition_assets: StorageVec<AssetId> = StorageVec {},
+
+    /// The identity proposed as new owner, until it accepts.
+    pending_owner: Option<Identity> = None,
+}
+
+configurable {
+    /// The address to which the builder fee will be sent.
+    ///
+    /// # Type
+    ///
+    /// `Address`
+    BUILDER_FEE_ADDRESS: Address = Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+
+    /// The fee amount to be paid to the builder.
+    ///
+    /// # Type
+    ///
+    /// `u64`
+    BUILDER_FEE: u64 = 0,
+
+    /// The address to which the builder's revenue share will be sent.
+    ///
+    /// # Type
+    ///
+    /// `Address`
+    BUILDER_REVENUE_SHARE_ADDRESS: Address = Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+
+    /// The percentage of revenue to be shared with the builder.
+    ///
+    /// This needs to be a value between 0 and 100.
+    ///
+    /// # Type
+    ///
+    /// `u64`
+    BUILDER_REVENUE_SHARE_PERCENTAGE: u64 = 0,
+
+    /// The percentage of revenue to be shared with the affiliate.
+    ///
+    /// This needs to be a value between 0 and 100.
+    ///
+    /// # Type
+    ///
+    /// `u64`
+    AFFILIATE_FEE_PERCENTAGE: u64 = 0,
+
+    /// A flag to disable the airdrop functionality.
+    ///
+    /// # Type
+    ///
+    /// `bool`
+    DISABLE_AIRDROP: bool = false,
+
+    /// The registry that is allowed to call the constructor.
+    ///
+    /// Must be set at deployment; with a zero id the constructor cannot be called at all.
+    ///
+    /// # Type
+    ///
+    /// `ContractId`
+    REGISTRY_CONTRACT_ID: ContractId = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+}
+
+#[storage(read)]
+fn _edition(editions: StorageKey<StorageMap<AssetId, Edition>>, asset: AssetId) -> Option<Edition> {
+    editions.get(asset).try_read()
+}
+
+fn _require_valid_mint_window(start: u64, end: u64) {
+    require(
+        start == 0 || end == 0 || start < end,
+        SetError::InvalidMintWindow,
+    );
+}
+
+fn _require_within_max_supply(edition: Edition, minted: u64, amount: u64) {
+    require(amount > 0, MintError::InvalidAmount);
+    require(
+        edition.max_supply == 0 || minted + amount <= edition.max_supply,
+        MintError::MaxSupplyReached,
+    );
+}
+
+/// Returns what minting `amount` copies at `price` charges, split exactly as `mint` pays it out.
+fn _quote_mint(price: u64, amount: u64, affiliate: Option<Identity>, protocol: ProtocolConfig) -> MintQuote {
+    require(amount > 0, MintError::InvalidAmount);
+
+    let subtotal = price.multiply(amount);
+
+    let builder_fee = if BUILDER_FEE_ADDRESS != Address::from(0x0000000000000000000000000000000000000000000000000000000000000000) {
+        BUILDER_FEE
+    } else {
+        0
+    };
+
+    let builder_revenue_share = if BUILDER_REVENUE_SHARE_ADDRESS != Address::from(0x0000000000000000000000000000000000000000000000000000000000000000) {
+        (subtotal * BUILDER_REVENUE_SHARE_PERCENTAGE) / 100
+    } else {
+        0
+    };
+
+    let affiliate_fee = match affiliate {
+        Some(Identity::Address(_)) => (subtotal * affiliate_fee_percentage(protocol, AFFILIATE_FEE_PERCENTAGE, BUILDER_REVENUE_SHARE_PERCENTAGE)) / 100,
+        _ => 0,
+    };
+
+    let fee_splitter = abi(PropsFeeSplitter, fee_contract_id(protocol, FEE_CONTRACT_ID));
+    let protocol_fee = fee_splitter.quote_fee_for(ContractId::this(), price, amount);
+
+    // The fixed builder fee is always charged and goes to the creator when no builder is set
+    let total = subtotal + protocol_fee + BUILDER_FEE;
+
+    MintQuote {
+        creator_price: total - protocol_fee - builder_fee - builder_revenue_share - affiliate_fee,
+        protocol_fee,
+        builder_fee,
+        builder_revenue_share,
+        affiliate_fee,
+        total,
+    }
+}
+
+impl SRC20 for Contract {
+    /// Returns the number of editions that have been minted.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of assets that this contract has minted.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src20::SRC20;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let contract_abi = abi(SRC20, contract_id);
+    ///     let total_assets = contract_abi.total_assets();
+    ///     assert(total_assets != 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        _total_assets(storage.total_assets)
+    }
+
+    /// Returns the number of copies minted for an edition.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset of which to query the total supply.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The total supply of coins for `asset`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src20::SRC20;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let contract_abi = abi(SRC20, contract_id);
+    ///     let total_supply = contract_abi.total_supply(asset).unwrap();
+    ///     assert(total_supply >= 1);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        _total_supply(storage.total_supply, asset)
+    }
+
+    /// Returns the name of an edition.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset of which to query the name.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<String>] - The name of `asset`, or `None` if no such edition exists.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src20::SRC20;
+    /// use std::string::String;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let contract_abi = abi(SRC20, contract_id);
+    ///     let name = contract_abi.name(asset).unwrap();
+    ///     assert(name.len() != 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        match _edition(storage.editions, asset) {
+            Some(_) => storage.name.read_slice(),
+            None => None,
+        }
+    }
+
+    /// Returns the symbol of an edition.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset of which to query the symbol.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<String>] - The symbol of `asset`, or `None` if no such edition exists.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src20::SRC20;
+    /// use std::string::String;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let contract_abi = abi(SRC20, contract_id);
+    ///     let symbol = contract_abi.symbol(asset).unwrap();
+    ///     assert(symbol.len() != 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        match _edition(storage.editions, asset) {
+            Some(_) => storage.symbol.read_slice(),
+            None => None,
+        }
+    }
+
+    /// Returns the number of decimals an edition uses.
+    ///
+    /// # Additional Information
+    ///
+    /// Copies of an edition are indivisible, so the decimals are always 0u8.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset of which to query the decimals.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u8>] - The decimal precision used by `asset`, or `None` if no such edition exists.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src20::SRC20;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssedId) {
+    ///     let contract_abi = abi(SRC20, contract_id);
+    ///     let decimals = contract_abi.decimals(asset).unwrap();
+    ///     assert(decimals == 0u8);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        match _edition(storage.editions, asset) {
+            Some(_) => Some(0u8),
+            None => None,
+        }
+    }
+}
+
+impl SRC7 for Contract {
+    /// Returns metadata for the corresponding `asset` and `key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset of which to query the metadata.
+    /// * `key`: [String] - The key to the specific metadata.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Metadata>] - `Some` metadata that corresponds to the `key` or `None`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use src_7::{SRC7, Metadata};
+    /// use std::string::String;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let contract_abi = abi(SRC7, contract_id);
+    ///     let key = String::from_ascii_str("image");
+    ///     let data = contract_abi.metadata(asset, key);
+    ///     assert(data.is_some());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+        storage.metadata.get(asset, key)
+    }
+}
+
+impl SetAssetMetadata for Contract {
+    /// Stores metadata for an edition.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The edition for the metadata to be stored.
+    /// * `key`: [String] - The key for the metadata to be stored.
+    /// * `metadata`: [Metadata] - The metadata to be stored.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the metadata role.
+    /// * When the edition does not exist.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use asset::metdata::SetAssetMetadata;
+    /// use src_7::{SRC7, Metadata};
+    ///
+    /// fn foo(asset: AssetId, key: String, contract_id: ContractId, metadata: Metadata) {
+    ///     let set_abi = abi(SetAssetMetadata, contract_id);
+    ///     let src_7_abi = abi(SRC7, contract);
+    ///     set_abi.set_metadata(asset, key, metadata);
+    ///     assert(src_7_abi.metadata(asset, key) == metadata);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_metadata(asset: AssetId, key: String, metadata: Metadata) {
+        only_role(METADATA_ROLE);
+        require(_edition(storage.editions, asset).is_some(), SetError::EditionNotFound);
+        _set_metadata(storage.metadata, asset, key, metadata);
+        let sender = msg_sender().unwrap();
+        SetMetadataEvent::new(asset, Some(metadata), key, sender).log();
+    }
+}
+
+impl MultiEdition for Contract {
+    /// Creates a new edition with its own asset, metadata, price, supply cap and mint window.
+    ///
+    /// # Additional Information
+    ///
+    /// A `max_supply` of `0` allows an unlimited number of copies. A date of `0` leaves that side of
+    /// the mint window open.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_id`: [SubId] - The sub identifier of the edition's asset.
+    /// * `metadata_keys`: [Vec<String>] - The keys for the metadata.
+    /// * `metadata_values`: [Vec<Metadata>] - The values for the metadata.
+    /// * `price`: [u64] - The price of a single copy.
+    /// * `max_supply`: [u64] - The maximum number of copies.
+    /// * `start_date`: [u64] - The start date for minting.
+    /// * `end_date`: [u64] - The end date for minting.
+    ///
+    /// # Returns
+    ///
+    /// * [AssetId] - The asset of the new edition.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When an edition with `sub_id` already exists.
+    /// * When the number of metadata keys and values differ.
+    /// * When both dates are set and `start_date` is not before `end_date`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `3`
+    /// * Writes: `3 + metadata_keys.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEdition;
+    ///
+    /// fn foo(contract_id: ContractId, sub_id: SubId) {
+    ///     let edition_abi = abi(MultiEdition, contract_id);
+    ///     let asset = edition_abi.create_edition(sub_id, Vec::new(), Vec::new(), 100, 0, 0, 0);
+    ///     assert(edition_abi.edition(asset).is_some());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn create_edition(sub_id: SubId, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, max_supply: u64, start_date: u64, end_date: u64) -> AssetId {
+        only_owner();
+
+        let asset = AssetId::new(ContractId::this(), sub_id);
+        require(_edition(storage.editions, asset).is_none(), SetError::EditionAlreadyExists);
+        require(metadata_keys.len() == metadata_values.len(), SetError::LengthMismatch);
+        _require_valid_mint_window(start_date, end_date);
+
+        storage.editions.insert(asset, Edition {
+            sub_id,
+            price,
+            max_supply,
+            start_date,
+            end_date,
+        });
+        storage.edition_assets.push(asset);
+
+        let sender = msg_sender().unwrap();
+        let mut i = 0;
+        while i < metadata_keys.len() {
+            let key = metadata_keys.get(i).unwrap();
+            let value = metadata_values.get(i).unwrap();
+            _set_metadata(storage.metadata, asset, key, value);
+            SetMetadataEvent::new(asset, Some(value), key, sender).log();
+            i += 1;
+        }
+
+        SetNameEvent::new(asset, storage.name.read_slice(), sender).log();
+        SetSymbolEvent::new(asset, storage.symbol.read_slice(), sender).log();
+        SetDecimalsEvent::new(asset, 0u8, sender).log();
+
+        log(CreateEditionEvent {
+            asset,
+            sub_id,
+            price,
+            max_supply,
+            start_date,
+            end_date
+        });
+
+        asset
+    }
+
+    /// Sets the price of a single copy of an edition.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The edition to update.
+    /// * `price`: [u64] - The new price.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the finance role.
+    /// * When the edition does not exist.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEdition;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let edition_abi = abi(MultiEdition, contract_id);
+    ///     edition_abi.set_edition_price(asset, 200);
+    ///     assert(edition_abi.edition(asset).unwrap().price == 200);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_edition_price(asset: AssetId, price: u64) {
+        only_role(FINANCE_ROLE);
+
+        let edition = _edition(storage.editions, asset);
+        require(edition.is_some(), SetError::EditionNotFound);
+
+        let mut edition = edition.unwrap();
+        edition.price = price;
+        storage.editions.insert(asset, edition);
+
+        log(SetEditionPriceEvent {
+            asset,
+            price
+        });
+    }
+
+    /// Sets the mint window of an edition.
+    ///
+    /// # Additional Information
+    ///
+    /// Passing `0` for either date leaves that side of the mint window open.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The edition to update.
+    /// * `start`: [u64] - The start date to set, or `0` for no start date.
+    /// * `end`: [u64] - The end date to set, or `0` for no end date.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the metadata role.
+    /// * When the edition does not exist.
+    /// * When both dates are set and `start` is not before `end`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEdition;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let edition_abi = abi(MultiEdition, contract_id);
+    ///     edition_abi.set_edition_dates(asset, 1000, 2000);
+    ///     assert(edition_abi.edition(asset).unwrap().end_date == 2000);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_edition_dates(asset: AssetId, start: u64, end: u64) {
+        only_role(METADATA_ROLE);
+        _require_valid_mint_window(start, end);
+
+        let edition = _edition(storage.editions, asset);
+        require(edition.is_some(), SetError::EditionNotFound);
+
+        let mut edition = edition.unwrap();
+        edition.start_date = start;
+        edition.end_date = end;
+        storage.editions.insert(asset, edition);
+
+        log(SetEditionDatesEvent {
+            asset,
+            start,
+            end
+        });
+    }
+
+    /// Returns the configuration of an edition.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The edition to query.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Edition>] - The edition, or `None` if it does not exist.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEdition;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let edition_abi = abi(MultiEdition, contract_id);
+    ///     assert(edition_abi.edition(asset).is_some());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn edition(asset: AssetId) -> Option<Edition> {
+        _edition(storage.editions, asset)
+    }
+
+    /// Returns a page of edition assets in the order they were created.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: [u64] - The index of the first edition to return.
+    /// * `limit`: [u64] - The maximum number of editions to return.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<AssetId>] - The assets of the editions in the requested range.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1 + limit`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEdition;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let edition_abi = abi(MultiEdition, contract_id);
+    ///     let editions = edition_abi.editions(0, 10);
+    ///     assert(editions.len() <= 10);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn editions(offset: u64, limit: u64) -> Vec<AssetId> {
+        let mut editions = Vec::new();
+        let len = storage.edition_assets.len();
+
+        let mut i = offset;
+        while i < len && i - offset < limit {
+            editions.push(storage.edition_assets.get(i).unwrap().read());
+            i += 1;
+        }
+
+        editions
+    }
+
+    /// Returns the number of editions that have been created.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of editions.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEdition;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let edition_abi = abi(MultiEdition, contract_id);
+    ///     assert(edition_abi.total_editions() == 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn total_editions() -> u64 {
+        storage.edition_assets.len()
+    }
+}
+
+impl MultiEditionMint for Contract {
+    /// Mints copies of an edition to a recipient.
+    ///
+    /// # Additional Information
+    ///
+    /// All copies of an edition share a single asset, so minting increases the SRC-20 supply of that asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient`: [Identity] - The user to which the copies are transferred to.
+    /// * `sub_id`: [SubId] - The sub identifier of the edition.
+    /// * `amount`: [u64] - The number of copies to mint.
+    /// * `affiliate`: [Option<Identity>] - The affiliate that receives a share of the price.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract is paused.
+    /// * When `REGISTRY_CONTRACT_ID` is set and the registry has paused minting.
+    /// * When the edition does not exist.
+    /// * When `amount` is zero.
+    /// * When the current time is outside of the edition's mint window.
+    /// * When more than the edition's maximum supply would be minted.
+    /// * When the payment is not in the base asset or is too low.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `5`
+    /// * Writes: `3`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEditionMint;
+    ///
+    /// fn foo(contract_id: ContractId, sub_id: SubId) {
+    ///     let mint_abi = abi(MultiEditionMint, contract_id);
+    ///     mint_abi.mint {
+    ///         coins: 100,
+    ///         asset_id: AssetId::base().bits(),
+    ///     }(Identity::ContractId(ContractId::this()), sub_id, 1, None);
+    /// }
+    /// ```
+    #[storage(read, write), payable]
+    fn mint(recipient: Identity, sub_id: SubId, amount: u64, affiliate: Option<Identity>) {
+        reentrancy_guard();
+        require_not_paused();
+
+        let protocol = protocol_config(REGISTRY_CONTRACT_ID);
+        require(!protocol.paused, MintError::ProtocolPaused);
+
+        let asset = AssetId::new(ContractId::this(), sub_id);
+        let edition = _edition(storage.editions, asset);
+        require(edition.is_some(), MintError::EditionNotFound);
+        let edition = edition.unwrap();
+
+        // Checking mint dates
+        let current_time = timestamp();
+        require(
+            edition.start_date == 0 || current_time >= edition.start_date,
+            MintError::OutsideMintingPeriod(String::from_ascii_str("Minting has not started yet."))
+        );
+        require(
+            edition.end_date == 0 || current_time <= edition.end_date,
+            MintError::OutsideMintingPeriod(String::from_ascii_str("Minting has ended."))
+        );
+
+        let current_supply = _total_supply(storage.total_supply, asset).unwrap_or(0);
+        let minted = storage.minted.get(asset).try_read().unwrap_or(0);
+        _require_within_max_supply(edition, minted, amount);
+
+        let price_amount = msg_amount();
+        require(msg_asset_id() == AssetId::base(), MintError::InvalidAsset);
+
+        let quote = _quote_mint(edition.price, amount, affiliate, protocol);
+        let total_price = quote.total;
+        let fee = quote.protocol_fee;
+        let affiliate_fee = quote.affiliate_fee;
+        let total_fee = fee + quote.builder_fee + quote.builder_revenue_share + affiliate_fee;
+
+        require(price_amount >= total_price, MintError::NotEnoughTokens(total_price));
+
+        // Transfer builder and affiliate fees
+        if quote.builder_fee > 0 {
+            transfer(Identity::Address(BUILDER_FEE_ADDRESS), AssetId::base(), quote.builder_fee);
+        }
+
+        if quote.builder_revenue_share > 0 {
+            transfer(Identity::Address(BUILDER_REVENUE_SHARE_ADDRESS), AssetId::base(), quote.builder_revenue_share);
+        }
+
+        if affiliate_fee > 0 {
+            if let Some(Identity::Address(affiliate_address)) = affiliate {
+                transfer(Identity::Address(affiliate_address), AssetId::base(), affiliate_fee);
+            }
+        }
+
+        let fee_splitter = abi(PropsFeeSplitter, fee_contract_id(protocol, FEE_CONTRACT_ID));
+
+        if fee > 0 {
+            fee_splitter.receive_funds {
+                coins: fee,
+                asset_id: AssetId::base().bits(),
+            }();
+        }
+
+        let creator_price = price_amount - total_fee; // Allows giving tips to the creator
+
+        // Transfer the remaining amount to the owner
+        if creator_price > 0 {
+            if let State::Initialized(owner_identity) = _owner() {
+                if let Identity::Address(owner_address) = owner_identity {
+                    transfer(Identity::Address(owner_address), AssetId::base(), creator_price);
+                }
+            }
+        }
+
+        let _ = _mint(
+            storage.total_assets,
+            storage.total_supply,
+            recipient,
+            sub_id,
+            amount,
+        );
+        storage.minted.insert(asset, minted + amount);
+
+        TotalSupplyEvent::new(asset, current_supply + amount, msg_sender().unwrap()).log();
+
+        log(EditionMintEvent {
+            recipient,
+            asset,
+            amount,
+            affiliate: affiliate.unwrap_or(Identity::Address(Address::from(0x0000000000000000000000000000000000000000000000000000000000000000))),
+            total_price,
+            total_fee,
+            price_amount,
+            builder_fee: BUILDER_FEE,
+            affiliate_fee,
+            fee,
+            creator_price
+        });
+    }
+
+    /// Returns exactly what minting copies of an edition charges and how the payment is split.
+    ///
+    /// # Additional Information
+    ///
+    /// `mint` uses the same calculation, so forwarding `total` always covers the mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient`: [Identity] - The identity that would receive the copies. The price does not currently depend on it.
+    /// * `sub_id`: [SubId] - The sub identifier of the edition.
+    /// * `amount`: [u64] - The number of copies to mint.
+    /// * `affiliate`: [Option<Identity>] - The affiliate that would receive a share of the price.
+    ///
+    /// # Returns
+    ///
+    /// * [MintQuote] - The creator price, each fee and the total to forward.
+    ///
+    /// # Reverts
+    ///
+    /// * When the edition does not exist.
+    /// * When `amount` is zero.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEditionMint;
+    ///
+    /// fn foo(contract_id: ContractId, recipient: Identity, sub_id: SubId) {
+    ///     let mint_abi = abi(MultiEditionMint, contract_id);
+    ///     let quote = mint_abi.quote_mint(recipient, sub_id, 2, None);
+    ///     assert(quote.total >= quote.creator_price);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn quote_mint(recipient: Identity, sub_id: SubId, amount: u64, affiliate: Option<Identity>) -> MintQuote {
+        let edition = _edition(storage.editions, AssetId::new(ContractId::this(), sub_id));
+        require(edition.is_some(), MintError::EditionNotFound);
+
+        _quote_mint(edition.unwrap().price, amount, affiliate, protocol_config(REGISTRY_CONTRACT_ID))
+    }
+
+    /// Mints copies of an edition to a recipient free of charge. Only callable by the owner or a minter.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient`: [Identity] - The user to which the copies are transferred to.
+    /// * `sub_id`: [SubId] - The sub identifier of the edition.
+    /// * `amount`: [u64] - The number of copies to mint.
+    ///
+    /// # Reverts
+    ///
+    /// * When airdrops are disabled.
+    /// * When the caller is not the contract owner and does not have the minter role.
+    /// * When the contract is paused.
+    /// * When the edition does not exist.
+    /// * When `amount` is zero.
+    /// * When more than the edition's maximum supply would be minted.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `5`
+    /// * Writes: `3`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEditionMint;
+    ///
+    /// fn foo(contract_id: ContractId, sub_id: SubId) {
+    ///     let mint_abi = abi(MultiEditionMint, contract_id);
+    ///     mint_abi.airdrop(Identity::ContractId(ContractId::this()), sub_id, 10);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn airdrop(recipient: Identity, sub_id: SubId, amount: u64) {
+        require(!DISABLE_AIRDROP, "Airdrop is disabled");
+        only_role(MINTER_ROLE);
+        require_not_paused();
+
+        let asset = AssetId::new(ContractId::this(), sub_id);
+        let edition = _edition(storage.editions, asset);
+        require(edition.is_some(), MintError::EditionNotFound);
+
+        let current_supply = _total_supply(storage.total_supply, asset).unwrap_or(0);
+        let minted = storage.minted.get(asset).try_read().unwrap_or(0);
+        _require_within_max_supply(edition.unwrap(), minted, amount);
+
+        let _ = _mint(
+            storage.total_assets,
+            storage.total_supply,
+            recipient,
+            sub_id,
+            amount,
+        );
+        storage.minted.insert(asset, minted + amount);
+
+        TotalSupplyEvent::new(asset, current_supply + amount, msg_sender().unwrap()).log();
+
+        log(EditionAirdropEvent {
+            recipient,
+            asset,
+            amount
+        });
+    }
+
+    /// Burns copies of an edition sent with the given `sub_id`.
+    ///
+    /// # Additional Information
+    ///
+    /// NOTE: The sha-256 hash of `(ContractId, SubId)` must match the `AssetId` where `ContractId` is the id of
+    /// the implementing contract and `SubId` is the given `sub_id` argument.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_id`: [SubId] - The sub-identifier of the asset to burn.
+    /// * `amount`: [u64] - The quantity of coins to burn.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract is paused.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use interface::MultiEditionMint;
+    ///
+    /// fn foo(contract_id: ContractId, asset_id: AssetId, sub_id: SubId) {
+    ///     let mint_abi = abi(MultiEditionMint, contract_id);
+    ///     mint_abi.burn {
+    ///         gas: 10000,
+    ///         coins: 1,
+    ///         asset_id: asset_id.bits(),
+    ///     } (sub_id, 1);
+    /// }
+    /// ```
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64) {
+        require_not_paused();
+        _burn(storage.total_supply, sub_id, amount);
+
+        let asset = AssetId::new(ContractId::this(), sub_id);
+        TotalSupplyEvent::new(asset, _total_supply(storage.total_supply, asset).unwrap_or(0), msg_sender().unwrap()).log();
+
+        log(BurnEvent {
+            amount,
+            sub_id
+        });
+    }
+}
+
+impl Sweep for Contract {
+    /// Transfers assets held by the contract to a recipient.
+    ///
+    /// # Additional Information
+    ///
+    /// Mint proceeds and fees are forwarded during `mint`, so the contract holds no funds owed to
+    /// anyone. This recovers assets sent by mistake, NFTs returned to the contract and the proceeds
+    /// kept for an owner that is a `ContractId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to transfer.
+    /// * `amount`: [u64] - The amount of `asset` to transfer.
+    /// * `recipient`: [Identity] - The identity receiving the assets.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When `amount` is zero.
+    /// * When `amount` is greater than the balance of `asset`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Sweep;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId, recipient: Identity) {
+    ///     let sweep_abi = abi(Sweep, contract_id);
+    ///     sweep_abi.sweep(asset, sweep_abi.sweepable(asset), recipient);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn sweep(asset: AssetId, amount: u64, recipient: Identity) {
+        _sweep(asset, amount, recipient, this_balance(asset));
+    }
+
+    /// Returns how much of an asset the owner can sweep.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to check.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The balance of `asset` held by the contract.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Sweep;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let sweep_abi = abi(Sweep, contract_id);
+    ///     assert(sweep_abi.sweepable(asset) == 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn sweepable(asset: AssetId) -> u64 {
+        this_balance(asset)
+    }
+}
+
+impl SRC5 for Contract {
+    /// Returns the owner.
+    ///
+    /// # Return Values
+    ///
+    /// * [State] - Represents the state of ownership for this contract.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use standards::src5::SRC5;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownership_abi = abi(contract_id, SRC_5);
+    ///
+    ///     match ownership_abi.owner() {
+    ///         State::Uninitalized => log("The ownership is uninitalized"),
+    ///         State::Initialized(owner) => log("The ownership is initalized"),
+    ///         State::Revoked => log("The ownership is revoked"),
+    ///     }
+    /// }
+    /// ```
+    #[storage(read)]
+    fn owner() -> State {
+        _owner()
+    }
+}
+
+impl Ownable for Contract {
+    /// Proposes a new owner for the contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Ownership only changes once `new_owner` calls `accept_ownership`. Proposing another
+    /// identity replaces the pending proposal.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner`: [Identity] - The identity proposed as new owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    ///
+    /// fn foo(contract_id: ContractId, new_owner: Identity) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.transfer_ownership(new_owner);
+    ///     assert(ownable_abi.pending_owner() == Some(new_owner));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        _transfer_ownership(new_owner, storage.pending_owner);
+    }
+
+    /// Accepts a pending ownership transfer, making the caller the owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the pending owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    /// use standards::src5::{SRC5, State};
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.accept_ownership();
+    ///     assert(abi(SRC5, contract_id).owner() == State::Initialized(msg_sender().unwrap()));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn accept_ownership() {
+        _accept_ownership(storage.pending_owner);
+    }
+
+    /// Permanently renounces ownership of the contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Any pending ownership transfer is cancelled. Owner-only functions can no longer be called afterwards.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    /// use standards::src5::{SRC5, State};
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.renounce_ownership();
+    ///     assert(abi(SRC5, contract_id).owner() == State::Revoked);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn renounce_ownership() {
+        _renounce_ownership(storage.pending_owner);
+    }
+
+    /// Returns the identity that has been proposed as new owner.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Identity>] - The pending owner, or `None` if no transfer is pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     assert(ownable_abi.pending_owner().is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity> {
+        storage.pending_owner.read()
+    }
+}
+
+impl AccessControl for Contract {
+    /// Grants a role to an account.
+    ///
+    /// # Additional Information
+    ///
+    /// The owner implicitly holds every role. Available roles are `MINTER_ROLE` (airdrop),
+    /// `METADATA_ROLE` (metadata and edition dates), `PAUSER_ROLE` (pause and unpause) and `FINANCE_ROLE` (edition prices).
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to grant.
+    /// * `account`: [Identity] - The account that receives the role.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::{AccessControl, roles::MINTER_ROLE};
+    ///
+    /// fn foo(contract_id: ContractId, bot: Identity) {
+    ///     let access_abi = abi(AccessControl, contract_id);
+    ///     access_abi.grant_role(MINTER_ROLE, bot);
+    ///     assert(access_abi.has_role(MINTER_ROLE, bot));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity) {
+        _grant_role(role, account);
+    }
+
+    /// Revokes a role from an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to revoke.
+    /// * `account`: [Identity] - The account that loses the role.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::{AccessControl, roles::MINTER_ROLE};
+    ///
+    /// fn foo(contract_id: ContractId, bot: Identity) {
+    ///     let access_abi = abi(AccessControl, contract_id);
+    ///     access_abi.revoke_role(MINTER_ROLE, bot);
+    ///     assert(!access_abi.has_role(MINTER_ROLE, bot));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn revoke_role(role: b256, account: Identity) {
+        _revoke_role(role, account);
+    }
+
+    /// Returns whether an account has been granted a role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to check.
+    /// * `account`: [Identity] - The account to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if `account` has been granted `role`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::{AccessControl, roles::PAUSER_ROLE};
+    ///
+    /// fn foo(contract_id: ContractId, account: Identity) {
+    ///     let access_abi = abi(AccessControl, contract_id);
+    ///     assert(!access_abi.has_role(PAUSER_ROLE, account));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn has_role(role: b256, account: Identity) -> bool {
+        _has_role(role, account)
+    }
+}
+
+impl Pausable for Contract {
+    /// Pauses the contract.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the pauser role.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use sway_libs::pausable::Pausable;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let pausable_abi = abi(Pausable, contract_id);
+    ///     pausable_abi.pause();
+    ///     assert(pausable_abi.is_paused());
+    /// }
+    /// ```
+    #[storage(write)]
+    fn pause() {
+        only_role(PAUSER_ROLE);
+        _pause();
+    }
+
+    /// Returns whether the contract is paused.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - The pause state for the contract.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use sway_libs::pausable::Pausable;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let pausable_abi = abi(Pausable, contract_id);
+    ///     assert(!pausable_abi.is_paused());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn is_paused() -> bool {
+        _is_paused()
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the pauser role.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use sway_libs::pausable::Pausable;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let pausable_abi = abi(Pausable, contract_id);
+    ///     pausable_abi.unpause();
+    ///     assert(!pausable_abi.is_paused());
+    /// }
+    /// ```
+    #[storage(write)]
+    fn unpause() {
+        only_role(PAUSER_ROLE);
+        _unpause();
+    }
+}
+
+impl Props1155Edition for Contract {
+    /// Sets the defaults for the contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner`: [Identity] - The `Identity` that will be the first owner.
+    /// * `name`: [String] - The name shared by all editions.
+    /// * `symbol`: [String] - The symbol shared by all editions.
+    ///
+    /// # Reverts
+    ///
+    /// * When `REGISTRY_CONTRACT_ID` is not set or the caller is not the registry.
+    /// * When `BUILDER_REVENUE_SHARE_PERCENTAGE` and `AFFILIATE_FEE_PERCENTAGE` add up to more than 100.
+    /// * When ownership has been set before.
+    ///
+    /// # Number of Storage Acesses
+    ///
+    /// * Reads: `1`
+    /// * Write: `3`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use standards::src5::SRC5;
+    /// use interface::Props1155Edition;
+    ///
+    /// fn foo(contract: ContractId, owner: Identity) {
+    ///     let src_5_abi = abi(SRC5, contract.bits());
+    ///     assert(src_5_abi.owner() == State::Uninitialized);
+    ///
+    ///     let constructor_abi = abi(Props1155Edition, contract.bits());
+    ///     constructor_abi.constructor(owner, String::from_ascii_str("Series"), String::from_ascii_str("SER"));
+    ///     assert(src_5_abi.owner() == State::Initialized(owner));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn constructor(owner: Identity, name: String, symbol: String) {
+        only_registry(REGISTRY_CONTRACT_ID);
+        require(
+            BUILDER_REVENUE_SHARE_PERCENTAGE + AFFILIATE_FEE_PERCENTAGE <= 100,
+            SetError::InvalidFeePercentages,
+        );
+        initialize_ownership(owner);
+
+        storage.name.write_slice(name);
+        storage.symbol.write_slice(symbol);
+    }
+}
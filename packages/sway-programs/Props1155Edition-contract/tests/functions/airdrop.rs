@@ -0,0 +1,125 @@
+use crate::utils::{
+    interface::{airdrop, burn, constructor, create_edition, grant_role, total_supply},
+    setup::{defaults, setup, default_name, default_symbol, default_price, default_max_supply, default_start_date, default_end_date, default_metadata_keys, default_metadata_values, get_wallet_balance, minter_role},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn airdrops_copies() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        airdrop(&instance_1, other_identity, sub_id_1, 3).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 3);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(3));
+    }
+
+    #[tokio::test]
+    async fn airdrops_with_minter_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, minter_role(), other_identity).await;
+
+        airdrop(&instance_2, other_identity, sub_id_1, 1).await;
+
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(1));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_missing_minter_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        airdrop(&instance_2, other_identity, sub_id_1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxSupplyReached")]
+    async fn when_max_supply_reached() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), 2, default_start_date(), default_end_date()).await;
+
+        airdrop(&instance_1, other_identity, sub_id_1, 3).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxSupplyReached")]
+    async fn when_burned_copies_would_be_minted_again() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), 2, default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, owner_identity, sub_id_1, 2).await;
+        burn(&instance_1, asset_id_1, sub_id_1, 1).await;
+
+        airdrop(&instance_1, owner_identity, sub_id_1, 1).await;
+    }
+}
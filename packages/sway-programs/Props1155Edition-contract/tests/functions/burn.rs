@@ -0,0 +1,33 @@
+use crate::utils::{
+    interface::{airdrop, burn, constructor, create_edition, total_supply},
+    setup::{defaults, setup, default_name, default_symbol, default_price, default_max_supply, default_start_date, default_end_date, default_metadata_keys, default_metadata_values, get_wallet_balance},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn burns_copies() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, owner_identity, sub_id_1, 3).await;
+
+        burn(&instance_1, asset_id_1, sub_id_1, 2).await;
+
+        assert_eq!(get_wallet_balance(&owner_wallet, &asset_id_1).await, 1);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(1));
+    }
+}
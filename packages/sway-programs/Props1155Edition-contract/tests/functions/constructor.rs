@@ -0,0 +1,106 @@
+use crate::utils::{
+    interface::{constructor, owner, name, symbol, create_edition},
+    setup::{deploy_without_registry, defaults, setup, default_name, default_symbol, default_price, default_max_supply, default_start_date, default_end_date, default_metadata_keys, default_metadata_values, State},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn initializes() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        assert_eq!(owner(&instance_1).await, State::Uninitialized);
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(owner(&instance_1).await, State::Initialized(owner_identity));
+        assert_eq!(name(&instance_1, asset_id_1).await, Some(default_name()));
+        assert_eq!(symbol(&instance_1, asset_id_1).await, Some(default_symbol()));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotReinitialized")]
+    async fn when_initialized_twice() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRegistry")]
+    async fn when_caller_is_not_registry() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        instance_1
+            .methods()
+            .constructor(owner_identity, default_name(), default_symbol())
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRegistry")]
+    async fn when_registry_is_not_set() {
+        let (owner_wallet, other_wallet, id, _instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        let instance = deploy_without_registry(&owner_wallet).await;
+
+        instance
+            .methods()
+            .constructor(owner_identity, default_name(), default_symbol())
+            .call()
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,152 @@
+use crate::utils::{
+    interface::{constructor, create_edition, decimals, edition, editions, metadata, total_editions},
+    setup::{defaults, setup, default_name, default_symbol, default_price, default_max_supply, default_start_date, default_end_date, default_metadata_keys, default_metadata_values, Metadata},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn creates_edition() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        let response = create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), 10, default_max_supply(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(response.value, asset_id_1);
+        let created = edition(&instance_1, asset_id_1).await.unwrap();
+        assert_eq!(created.sub_id, sub_id_1);
+        assert_eq!(created.price, 10);
+        assert_eq!(created.max_supply, default_max_supply());
+        assert_eq!(created.start_date, default_start_date());
+        assert_eq!(created.end_date, default_end_date());
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("name")).await,
+            Some(Metadata::String(String::from("Props Series #1")))
+        );
+        assert_eq!(decimals(&instance_1, asset_id_1).await, Some(0));
+    }
+
+    #[tokio::test]
+    async fn creates_multiple_editions() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+        create_edition(&instance_1, sub_id_2, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(total_editions(&instance_1).await, 2);
+        assert_eq!(editions(&instance_1, 0, 10).await, vec![asset_id_1, asset_id_2]);
+        assert_eq!(editions(&instance_1, 1, 10).await, vec![asset_id_2]);
+        assert!(edition(&instance_1, asset_id_1).await.is_some());
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        create_edition(&instance_2, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "EditionAlreadyExists")]
+    async fn when_edition_already_exists() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "LengthMismatch")]
+    async fn when_metadata_lengths_differ() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), vec![], default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidMintWindow")]
+    async fn when_start_date_is_not_before_end_date() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_end_date(), default_start_date()).await;
+    }
+}
@@ -0,0 +1,128 @@
+use crate::utils::{
+    interface::{add_template, contract_type, init_multi_edition, owner},
+    setup::{defaults, deployment, registry_contract_id, setup, template_root, unconfigured_bytecode, default_name, default_symbol, ContractType, Deployment, Props1155Edition, PropsRegistry, State},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn initializes_and_registers_through_registry() {
+        let (owner_wallet, other_wallet, id, _instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        let registry = PropsRegistry::new(registry_contract_id(), owner_wallet.clone());
+
+        let response = init_multi_edition(&registry, id, owner_identity, default_name(), default_symbol(), deployment(id)).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("RegisterEvent")));
+
+        let instance = Props1155Edition::new(id, owner_wallet.clone());
+        assert_eq!(owner(&instance).await, State::Initialized(owner_identity));
+        assert_eq!(contract_type(&registry, id).await, Some(ContractType::MultiEdition));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "UnknownBytecode")]
+    async fn when_template_not_allowlisted() {
+        let (owner_wallet, other_wallet, id, _instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        let registry = PropsRegistry::new(registry_contract_id(), owner_wallet.clone());
+
+        // Only the configured bytecode is allowlisted
+        let deployment = Deployment {
+            bytecode: unconfigured_bytecode(),
+            ..deployment(id)
+        };
+
+        init_multi_edition(&registry, id, owner_identity, default_name(), default_symbol(), deployment).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "WrongContractType")]
+    async fn when_template_is_another_contract_type() {
+        let (owner_wallet, other_wallet, id, _instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        let registry = PropsRegistry::new(registry_contract_id(), owner_wallet.clone());
+        add_template(&registry, template_root(), ContractType::Edition).await;
+
+        init_multi_edition(&registry, id, owner_identity, default_name(), default_symbol(), deployment(id)).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CallerNotOwner")]
+    async fn when_caller_is_not_owner() {
+        let (owner_wallet, other_wallet, id, _instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        // Replaying the owner's public proof from another wallet must fail
+        let registry = PropsRegistry::new(registry_contract_id(), other_wallet.clone());
+
+        init_multi_edition(&registry, id, owner_identity, default_name(), default_symbol(), deployment(id)).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidDeploymentProof")]
+    async fn when_deployment_was_made_for_another_owner() {
+        let (owner_wallet, other_wallet, id, _instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            _owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        // Front-running the owner with a different identity must fail
+        let registry = PropsRegistry::new(registry_contract_id(), other_wallet.clone());
+
+        init_multi_edition(&registry, id, other_identity, default_name(), default_symbol(), deployment(id)).await;
+    }
+}
@@ -0,0 +1,331 @@
+use crate::utils::{
+    interface::{constructor, create_edition, fee_constructor, mint, pause, set_fee, set_fee_contract, total_assets, total_supply},
+    setup::{defaults, deploy_fee_splitter, registry_contract_id, setup, default_name, default_symbol, default_price, default_max_supply, default_start_date, default_end_date, default_metadata_keys, default_metadata_values, get_wallet_balance, PropsRegistry},
+};
+use fuels::prelude::{AssetId, CallParameters, ContractId};
+use tai64::Tai64;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mints_copies_of_an_edition() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 5, 0, None).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 5);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(5));
+        assert_eq!(total_assets(&instance_1).await, 1);
+    }
+
+    #[tokio::test]
+    async fn mints_multiple_editions() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+        create_edition(&instance_1, sub_id_2, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 2, 0, None).await;
+        mint(&instance_1, other_identity, sub_id_2, 3, 0, None).await;
+
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(2));
+        assert_eq!(total_supply(&instance_1, asset_id_2).await, Some(3));
+        assert_eq!(total_assets(&instance_1).await, 2);
+    }
+
+    #[tokio::test]
+    async fn mints_unlimited_edition() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), 0, 0, 0).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 100, 0, None).await;
+
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(100));
+    }
+
+    #[tokio::test]
+    async fn mints_with_payment() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), 10, default_max_supply(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_2, other_identity, sub_id_1, 2, 20, None).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 2);
+    }
+
+    #[tokio::test]
+    async fn pays_fee_to_registry_fee_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        let protocol_fee_instance = deploy_fee_splitter(&owner_wallet, 2).await;
+        let protocol_fee_id = ContractId::from(protocol_fee_instance.contract_id());
+        fee_constructor(&protocol_fee_instance, owner_identity).await;
+        set_fee(&protocol_fee_instance, 1_000).await;
+
+        let registry = PropsRegistry::new(registry_contract_id(), owner_wallet);
+        set_fee_contract(&registry, Some(protocol_fee_id)).await;
+
+        // The fee contract the edition was deployed with is no longer called
+        instance_2
+            .methods()
+            .mint(other_identity, sub_id_1, 1, None)
+            .with_contract_ids(&[protocol_fee_id.into(), registry_contract_id().into()])
+            .append_variable_outputs(4)
+            .call_params(CallParameters::new(1_000, AssetId::zeroed(), 1_000_000))
+            .unwrap()
+            .call()
+            .await
+            .unwrap();
+
+        let fee_contract_balances = protocol_fee_instance.get_balances().await.unwrap();
+        assert_eq!(fee_contract_balances.get(&AssetId::zeroed()).copied().unwrap_or(0), 1_000);
+
+        let default_fee_contract_balances = fee_instance_1.get_balances().await.unwrap();
+        assert_eq!(default_fee_contract_balances.get(&AssetId::zeroed()).copied().unwrap_or(0), 0);
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 1);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "EditionNotFound")]
+    async fn when_edition_not_found() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxSupplyReached")]
+    async fn when_max_supply_reached() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, default_max_supply() + 1, 0, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidAmount")]
+    async fn when_amount_is_zero() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 0, 0, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotEnoughTokens")]
+    async fn when_not_enough_tokens() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), 10, default_max_supply(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_2, other_identity, sub_id_1, 2, 10, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OutsideMintingPeriod")]
+    async fn when_minting_before_start_date() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        let current_time = Tai64::now().0;
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), current_time + 3600, current_time + 7200).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OutsideMintingPeriod")]
+    async fn when_minting_after_end_date() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        let current_time = Tai64::now().0;
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), current_time - 7200, current_time - 3600).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ProtocolPaused")]
+    async fn when_protocol_paused() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        let registry = PropsRegistry::new(registry_contract_id(), owner_wallet);
+        registry.methods().set_protocol_paused(true).call().await.unwrap();
+
+        mint(&instance_2, other_identity, sub_id_1, 1, 0, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Paused")]
+    async fn when_paused() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+        pause(&instance_1).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, None).await;
+    }
+}
@@ -0,0 +1,11 @@
+mod constructor;
+mod init_multi_edition;
+mod create_edition;
+mod set_edition_price;
+mod set_edition_dates;
+mod mint;
+mod airdrop;
+mod burn;
+mod set_metadata;
+mod sweep;
+mod quote_mint;
@@ -0,0 +1,85 @@
+use crate::utils::{
+    interface::{constructor, create_edition, mint, quote_mint, total_supply},
+    setup::{defaults, setup, default_name, default_symbol, default_max_supply, default_start_date, default_end_date, default_metadata_keys, default_metadata_values, get_wallet_balance, MintQuote},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn quotes_price_for_amount() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), 1_000, default_max_supply(), default_start_date(), default_end_date()).await;
+
+        let quote = quote_mint(&instance_1, other_identity, sub_id_1, 3, None).await;
+        assert_eq!(
+            quote,
+            MintQuote { creator_price: 3_000, protocol_fee: 0, builder_fee: 0, builder_revenue_share: 0, affiliate_fee: 0, total: 3_000 }
+        );
+
+        // Forwarding the quoted total is exactly enough to mint
+        mint(&instance_2, other_identity, sub_id_1, 3, quote.total, None).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 3);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(3));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "EditionNotFound")]
+    async fn when_edition_does_not_exist() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        quote_mint(&instance_1, other_identity, sub_id_1, 1, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidAmount")]
+    async fn when_amount_is_zero() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), 1_000, default_max_supply(), default_start_date(), default_end_date()).await;
+
+        quote_mint(&instance_1, other_identity, sub_id_1, 0, None).await;
+    }
+}
@@ -0,0 +1,105 @@
+use crate::utils::{
+    interface::{constructor, create_edition, edition, grant_role, set_edition_dates},
+    setup::{defaults, setup, metadata_role, default_name, default_symbol, default_price, default_max_supply, default_start_date, default_end_date, default_metadata_keys, default_metadata_values},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_edition_dates() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        set_edition_dates(&instance_1, asset_id_1, 0, 0).await;
+
+        let updated = edition(&instance_1, asset_id_1).await.unwrap();
+        assert_eq!(updated.start_date, 0);
+        assert_eq!(updated.end_date, 0);
+    }
+
+    #[tokio::test]
+    async fn sets_edition_dates_with_metadata_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, metadata_role(), other_identity).await;
+
+        set_edition_dates(&instance_2, asset_id_1, 0, 0).await;
+
+        let updated = edition(&instance_1, asset_id_1).await.unwrap();
+        assert_eq!(updated.start_date, 0);
+        assert_eq!(updated.end_date, 0);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_missing_metadata_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        set_edition_dates(&instance_2, asset_id_1, 0, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidMintWindow")]
+    async fn when_start_date_is_not_before_end_date() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        set_edition_dates(&instance_1, asset_id_1, default_end_date(), default_start_date()).await;
+    }
+}
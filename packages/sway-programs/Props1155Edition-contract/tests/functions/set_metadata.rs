@@ -0,0 +1,85 @@
+use crate::utils::{
+    interface::{constructor, create_edition, metadata, set_metadata},
+    setup::{defaults, setup, default_name, default_symbol, default_price, default_max_supply, default_start_date, default_end_date, default_metadata_keys, default_metadata_values, Metadata},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_edition_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+        create_edition(&instance_1, sub_id_2, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        set_metadata(&instance_1, asset_id_2, String::from("image"), Metadata::String(String::from("ipfs://two"))).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, String::from("image")).await,
+            Some(Metadata::String(String::from("ipfs://two")))
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("image")).await,
+            default_metadata_values().get(1).cloned()
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_missing_metadata_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+        create_edition(&instance_1, sub_id_1, default_metadata_keys(), default_metadata_values(), default_price(), default_max_supply(), default_start_date(), default_end_date()).await;
+
+        set_metadata(&instance_2, asset_id_1, String::from("image"), Metadata::String(String::from("ipfs://two"))).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "EditionNotFound")]
+    async fn when_edition_not_found() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol()).await;
+
+        set_metadata(&instance_1, asset_id_1, String::from("image"), Metadata::String(String::from("ipfs://two"))).await;
+    }
+}
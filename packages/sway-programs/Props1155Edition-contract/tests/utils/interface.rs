@@ -0,0 +1,350 @@
+use crate::utils::setup::{deployment, registry_contract_id, template_registry_offset, ContractType, Deployment, Edition, Metadata, MintQuote, State, Props1155Edition, PropsFeeSplitter, PropsRegistry};
+use chrono::{Duration, Utc};
+use fuels::{
+    accounts::{Account, ViewOnlyAccount},
+    prelude::{AssetId, CallParameters, TxPolicies, WalletUnlocked, ContractId, Bech32ContractId},
+    programs::{call_response::FuelCallResponse, call_utils::TxDependencyExtension},
+    types::{Bits256, Identity},
+};
+use std::str::FromStr;
+
+pub(crate) async fn total_assets(contract: &Props1155Edition<WalletUnlocked>) -> u64 {
+    contract
+        .methods()
+        .total_assets()
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn total_supply(contract: &Props1155Edition<WalletUnlocked>, asset: AssetId) -> Option<u64> {
+    contract
+        .methods()
+        .total_supply(asset)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn name(contract: &Props1155Edition<WalletUnlocked>, asset: AssetId) -> Option<String> {
+    contract.methods().name(asset).call().await.unwrap().value
+}
+
+pub(crate) async fn symbol(contract: &Props1155Edition<WalletUnlocked>, asset: AssetId) -> Option<String> {
+    contract.methods().symbol(asset).call().await.unwrap().value
+}
+
+pub(crate) async fn decimals(contract: &Props1155Edition<WalletUnlocked>, asset: AssetId) -> Option<u8> {
+    contract.methods().decimals(asset).call().await.unwrap().value
+}
+
+pub(crate) async fn mint(
+    contract: &Props1155Edition<WalletUnlocked>,
+    recipient: Identity,
+    sub_id: Bits256,
+    amount: u64,
+    price: u64,
+    affiliate: Option<Identity>,
+) -> FuelCallResponse<()> {
+    // @dev TODO: This is a hack to get the contract id, should be refactored
+    let id = Bech32ContractId::from(
+        ContractId::from_str("0xd65987a6b981810a28559d57e5083d47a10ce269cbf96316554d5b4a1b78485a")
+        .unwrap(),
+    );
+    contract
+        .methods()
+        .mint(recipient, sub_id, amount, affiliate)
+        .with_contract_ids(&[id.clone(), registry_contract_id().into()])
+        .append_variable_outputs(4)
+        .call_params(CallParameters::new(price, AssetId::zeroed(), 1_000_000))
+        .unwrap()
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn quote_mint(
+    contract: &Props1155Edition<WalletUnlocked>,
+    recipient: Identity,
+    sub_id: Bits256,
+    amount: u64,
+    affiliate: Option<Identity>,
+) -> MintQuote {
+    // @dev TODO: This is a hack to get the contract id, should be refactored
+    let id = Bech32ContractId::from(
+        ContractId::from_str("0xd65987a6b981810a28559d57e5083d47a10ce269cbf96316554d5b4a1b78485a")
+        .unwrap(),
+    );
+    contract
+        .methods()
+        .quote_mint(recipient, sub_id, amount, affiliate)
+        .with_contract_ids(&[id.clone(), registry_contract_id().into()])
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn airdrop(
+    contract: &Props1155Edition<WalletUnlocked>,
+    recipient: Identity,
+    sub_id: Bits256,
+    amount: u64,
+) -> FuelCallResponse<()> {
+    contract
+        .methods()
+        .airdrop(recipient, sub_id, amount)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn burn(
+    contract: &Props1155Edition<WalletUnlocked>,
+    asset_id: AssetId,
+    sub_id: Bits256,
+    amount: u64,
+) -> FuelCallResponse<()> {
+    let call_params = CallParameters::new(amount, asset_id, 1_000_000);
+
+    contract
+        .methods()
+        .burn(sub_id, amount)
+        .with_tx_policies(TxPolicies::default().with_script_gas_limit(2_000_000))
+        .call_params(call_params)
+        .unwrap()
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn owner(contract: &Props1155Edition<WalletUnlocked>) -> State {
+    contract.methods().owner().call().await.unwrap().value
+}
+
+pub(crate) async fn constructor(
+    contract: &Props1155Edition<WalletUnlocked>,
+    owner: Identity,
+    name: String,
+    symbol: String,
+) -> FuelCallResponse<()> {
+    // The contract only accepts its constructor from the registry, so initialize it through `init_multi_edition`
+    let contract_id = ContractId::from(contract.contract_id());
+    let registry = PropsRegistry::new(registry_contract_id(), contract.account());
+    registry
+        .methods()
+        .init_multi_edition(contract_id, owner, name, symbol, deployment(contract_id))
+        .with_contracts(&[contract])
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn create_edition(
+    contract: &Props1155Edition<WalletUnlocked>,
+    sub_id: Bits256,
+    metadata_keys: Vec<String>,
+    metadata_values: Vec<Metadata>,
+    price: u64,
+    max_supply: u64,
+    start_date: u64,
+    end_date: u64,
+) -> FuelCallResponse<AssetId> {
+    contract
+        .methods()
+        .create_edition(sub_id, metadata_keys, metadata_values, price, max_supply, start_date, end_date)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn set_edition_price(
+    contract: &Props1155Edition<WalletUnlocked>,
+    asset: AssetId,
+    price: u64,
+) -> FuelCallResponse<()> {
+    contract.methods().set_edition_price(asset, price).call().await.unwrap()
+}
+
+pub(crate) async fn set_edition_dates(
+    contract: &Props1155Edition<WalletUnlocked>,
+    asset: AssetId,
+    start: u64,
+    end: u64,
+) -> FuelCallResponse<()> {
+    contract.methods().set_edition_dates(asset, start, end).call().await.unwrap()
+}
+
+pub(crate) async fn edition(contract: &Props1155Edition<WalletUnlocked>, asset: AssetId) -> Option<Edition> {
+    contract.methods().edition(asset).call().await.unwrap().value
+}
+
+pub(crate) async fn editions(
+    contract: &Props1155Edition<WalletUnlocked>,
+    offset: u64,
+    limit: u64,
+) -> Vec<AssetId> {
+    contract.methods().editions(offset, limit).call().await.unwrap().value
+}
+
+pub(crate) async fn total_editions(contract: &Props1155Edition<WalletUnlocked>) -> u64 {
+    contract.methods().total_editions().call().await.unwrap().value
+}
+
+pub(crate) async fn metadata(
+    contract: &Props1155Edition<WalletUnlocked>,
+    asset: AssetId,
+    key: String,
+) -> Option<Metadata> {
+    contract
+        .methods()
+        .metadata(asset, key)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn set_metadata(
+    contract: &Props1155Edition<WalletUnlocked>,
+    asset: AssetId,
+    key: String,
+    metadata: Metadata,
+) -> FuelCallResponse<()> {
+    contract
+        .methods()
+        .set_metadata(asset, key, metadata)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn pause(contract: &Props1155Edition<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().pause().call().await.unwrap()
+}
+
+pub(crate) async fn grant_role(
+    contract: &Props1155Edition<WalletUnlocked>,
+    role: Bits256,
+    account: Identity,
+) -> FuelCallResponse<()> {
+    contract.methods().grant_role(role, account).call().await.unwrap()
+}
+
+pub(crate) async fn fee_constructor(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    owner: Identity,
+) -> FuelCallResponse<()> {
+    contract.methods().constructor(owner).call().await.unwrap()
+}
+
+pub(crate) async fn set_fee(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    fee: u64,
+) -> FuelCallResponse<()> {
+    let response = contract.methods().set_fee(fee).call().await.unwrap();
+
+    // Fee increases are timelocked, so move the chain past the delay and apply them
+    let pending = contract.methods().pending_fee(AssetId::zeroed()).call().await.unwrap().value;
+    if pending.is_some() {
+        skip_timelock(contract.account()).await;
+        contract.methods().apply_fee(AssetId::zeroed()).call().await.unwrap();
+    }
+
+    response
+}
+
+pub(crate) async fn set_fee_contract(
+    registry: &PropsRegistry<WalletUnlocked>,
+    fee_contract: Option<ContractId>,
+) -> FuelCallResponse<()> {
+    let response = registry.methods().set_fee_contract(fee_contract).call().await.unwrap();
+
+    // Fee contract changes are timelocked as well
+    skip_timelock(registry.account()).await;
+    registry.methods().apply_fee_contract().call().await.unwrap();
+
+    response
+}
+
+// Moves the chain a week and a day past its latest block, beyond every protocol timelock
+async fn skip_timelock(wallet: WalletUnlocked) {
+    let provider = wallet.try_provider().unwrap().clone();
+    let now = provider.latest_block_time().await.unwrap().unwrap_or_else(Utc::now);
+    provider
+        .produce_blocks(1, Some(now + Duration::days(8)))
+        .await
+        .unwrap();
+}
+
+pub(crate) async fn init_multi_edition(
+    registry: &PropsRegistry<WalletUnlocked>,
+    contract_id: ContractId,
+    owner: Identity,
+    name: String,
+    symbol: String,
+    deployment: Deployment,
+) -> FuelCallResponse<()> {
+    registry
+        .methods()
+        .init_multi_edition(contract_id, owner, name, symbol, deployment)
+        .with_contract_ids(&[contract_id.into()])
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn add_template(
+    registry: &PropsRegistry<WalletUnlocked>,
+    bytecode_root: Bits256,
+    contract_type: ContractType,
+) -> FuelCallResponse<()> {
+    registry
+        .methods()
+        .add_template(bytecode_root, contract_type, vec![], Some(template_registry_offset()))
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn contract_type(
+    registry: &PropsRegistry<WalletUnlocked>,
+    contract_id: ContractId,
+) -> Option<ContractType> {
+    registry.methods().contract_type(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn sweep(
+    contract: &Props1155Edition<WalletUnlocked>,
+    asset: AssetId,
+    amount: u64,
+    recipient: Identity,
+) -> FuelCallResponse<()> {
+    contract
+        .methods()
+        .sweep(asset, amount, recipient)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn sweepable(contract: &Props1155Edition<WalletUnlocked>, asset: AssetId) -> u64 {
+    contract.methods().sweepable(asset).call().await.unwrap().value
+}
+
+// Sends assets to the contract without calling any of its functions
+pub(crate) async fn transfer_to_contract(
+    wallet: &WalletUnlocked,
+    contract: &Props1155Edition<WalletUnlocked>,
+    amount: u64,
+    asset: AssetId,
+) {
+    wallet
+        .force_transfer_to_contract(contract.contract_id(), amount, asset, TxPolicies::default())
+        .await
+        .unwrap();
+}
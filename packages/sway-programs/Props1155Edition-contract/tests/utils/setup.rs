@@ -0,0 +1,324 @@
+use fuels::{
+    core::Configurables,
+    prelude::*,
+    types::{Address, AssetId, Bits256, Bytes32, Identity},
+};
+use sha2::{Digest, Sha256};
+use std::sync::Mutex;
+
+abigen!(Contract(
+    name = "Props1155Edition",
+    abi = "./Props1155Edition-contract/out/debug/Props1155Edition-contract-abi.json"
+),Contract(
+    name = "PropsFeeSplitter",
+    abi = "./PropsFeeSplitter-contract/out/debug/PropsFeeSplitter-contract-abi.json"
+),Contract(
+    name = "PropsRegistry",
+    abi = "./PropsRegistry-contract/out/debug/PropsRegistry-contract-abi.json"
+));
+
+const FEE_SPLITTER_CONTRACT_BINARY_PATH: &str = "../PropsFeeSplitter-contract/out/debug/PropsFeeSplitter-contract.bin";
+
+const NFT_CONTRACT_BINARY_PATH: &str = "./out/debug/Props1155Edition-contract.bin";
+
+const REGISTRY_CONTRACT_BINARY_PATH: &str = "../PropsRegistry-contract/out/debug/PropsRegistry-contract.bin";
+
+// The value every test deployment salt is derived from, together with its owner
+const DEPLOYMENT_SALT: [u8; 32] = [7u8; 32];
+
+// The deployment proofs of the contracts deployed by `setup`, handed to the registry on `constructor`
+static DEPLOYMENTS: Mutex<Vec<(ContractId, Deployment)>> = Mutex::new(Vec::new());
+
+pub(crate) fn defaults(
+    contract_id: ContractId,
+    wallet_1: WalletUnlocked,
+    wallet_2: WalletUnlocked,
+) -> (
+    AssetId,
+    AssetId,
+    AssetId,
+    Bits256,
+    Bits256,
+    Bits256,
+    Identity,
+    Identity,
+) {
+    let sub_id_1 = Bytes32::from([1u8; 32]);
+    let sub_id_2 = Bytes32::from([2u8; 32]);
+    let sub_id_3 = Bytes32::from([3u8; 32]);
+
+    let asset1 = get_asset_id(sub_id_1, contract_id);
+    let asset2 = get_asset_id(sub_id_2, contract_id);
+    let asset3 = get_asset_id(sub_id_3, contract_id);
+
+    let identity_1 = Identity::Address(Address::from(wallet_1.address()));
+    let identity_2 = Identity::Address(Address::from(wallet_2.address()));
+
+    (
+        asset1,
+        asset2,
+        asset3,
+        Bits256(*sub_id_1),
+        Bits256(*sub_id_2),
+        Bits256(*sub_id_3),
+        identity_1,
+        identity_2,
+    )
+}
+
+pub(crate) async fn setup() -> (
+    WalletUnlocked,
+    WalletUnlocked,
+    ContractId,
+    Props1155Edition<WalletUnlocked>,
+    Props1155Edition<WalletUnlocked>,
+    ContractId,
+    PropsFeeSplitter<WalletUnlocked>,
+) {
+    setup_with_configurables(Props1155EditionConfigurables::default()).await
+}
+
+async fn setup_with_configurables(configurables: Props1155EditionConfigurables) -> (
+    WalletUnlocked,
+    WalletUnlocked,
+    ContractId,
+    Props1155Edition<WalletUnlocked>,
+    Props1155Edition<WalletUnlocked>,
+    ContractId,
+    PropsFeeSplitter<WalletUnlocked>,
+) {
+    let number_of_coins = 1;
+    let coin_amount = 100_000_000;
+    let number_of_wallets = 2;
+
+    let base_asset = AssetConfig {
+        id: AssetId::zeroed(),
+        num_coins: number_of_coins,
+        coin_amount,
+    };
+    let assets = vec![base_asset];
+
+    let wallet_config = WalletsConfig::new_multiple_assets(number_of_wallets, assets);
+    let mut wallets = launch_custom_provider_and_get_wallets(wallet_config, None, None)
+        .await
+        .unwrap();
+
+    let wallet1 = wallets.pop().unwrap();
+    let wallet2 = wallets.pop().unwrap();
+
+    let id = deploy_multi_edition(&wallet1, configurables).await;
+
+    let instance_1 = Props1155Edition::new(id.clone(), wallet1.clone());
+    let instance_2 = Props1155Edition::new(id.clone(), wallet2.clone());
+
+    let fee_id = Contract::load_from(FEE_SPLITTER_CONTRACT_BINARY_PATH, LoadConfiguration::default())
+        .unwrap()
+        .deploy(&wallet1, TxPolicies::default())
+        .await
+        .unwrap();
+
+    let fee_instance_1 = PropsFeeSplitter::new(fee_id.clone(), wallet1.clone());
+
+    println!("fee_id hash: {:?}", fee_id.hash());
+
+    (wallet1, wallet2, id.into(), instance_1, instance_2, fee_id.into(), fee_instance_1)
+}
+
+// Deploys a fee splitter besides the one `setup` deploys, for tests that point the registry elsewhere
+pub(crate) async fn deploy_fee_splitter(wallet: &WalletUnlocked, salt: u8) -> PropsFeeSplitter<WalletUnlocked> {
+    let id = Contract::load_from(FEE_SPLITTER_CONTRACT_BINARY_PATH, LoadConfiguration::default().with_salt([salt; 32]))
+        .unwrap()
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+    PropsFeeSplitter::new(id, wallet.clone())
+}
+
+// The registry deployed by `setup` always lands at the same id for a given binary
+pub(crate) fn registry_contract_id() -> ContractId {
+    Contract::load_from(REGISTRY_CONTRACT_BINARY_PATH, LoadConfiguration::default())
+        .unwrap()
+        .contract_id()
+}
+
+// The template `setup` allowlists: the multi-edition bytecode configured with the registry
+pub(crate) fn template_root() -> Bits256 {
+    let configurables = Props1155EditionConfigurables::default()
+        .with_REGISTRY_CONTRACT_ID(registry_contract_id()).unwrap();
+    let contract = Contract::load_from(NFT_CONTRACT_BINARY_PATH, LoadConfiguration::default()
+        .with_configurables(configurables)
+    )
+        .unwrap();
+    Bits256(*contract.code_root())
+}
+
+// The byte offset of the `REGISTRY_CONTRACT_ID` configurable in the template `setup` allowlists
+pub(crate) fn template_registry_offset() -> u64 {
+    let configurables = Props1155EditionConfigurables::default()
+        .with_REGISTRY_CONTRACT_ID(registry_contract_id()).unwrap();
+    let mut bytecode = unconfigured_bytecode();
+    Configurables::from(configurables).update_constants_in(&mut bytecode);
+    registry_offset(&bytecode, registry_contract_id())
+}
+
+// The multi-edition bytecode before any configurables are applied
+pub(crate) fn unconfigured_bytecode() -> Vec<u8> {
+    std::fs::read(NFT_CONTRACT_BINARY_PATH).unwrap()
+}
+
+pub(crate) fn deployment(contract_id: ContractId) -> Deployment {
+    DEPLOYMENTS
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(id, _)| *id == contract_id)
+        .map(|(_, deployment)| deployment.clone())
+        .unwrap()
+}
+
+// The byte offset of the `REGISTRY_CONTRACT_ID` configurable in configured `bytecode`
+pub(crate) fn registry_offset(bytecode: &[u8], registry_id: ContractId) -> u64 {
+    bytecode.windows(32).position(|window| window == *registry_id).unwrap() as u64
+}
+
+fn deployment_salt(owner: Identity) -> [u8; 32] {
+    // The registry only initializes deployments whose salt is derived from the owner
+    let owner_bits = match owner {
+        Identity::Address(address) => *address,
+        Identity::ContractId(contract_id) => *contract_id,
+    };
+    let mut hasher = Sha256::new();
+    hasher.update(owner_bits);
+    hasher.update(DEPLOYMENT_SALT);
+    <[u8; 32]>::from(hasher.finalize())
+}
+
+// Deploys the registry and a multi-edition contract that only it can initialize, owned by `wallet`
+async fn deploy_multi_edition(wallet: &WalletUnlocked, configurables: Props1155EditionConfigurables) -> Bech32ContractId {
+    let registry_id = Contract::load_from(REGISTRY_CONTRACT_BINARY_PATH, LoadConfiguration::default())
+        .unwrap()
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+    println!("registry_id hash: {:?}", registry_id.hash());
+
+    let owner = Identity::Address(Address::from(wallet.address()));
+    let registry = PropsRegistry::new(registry_id.clone(), wallet.clone());
+    registry.methods().constructor(owner).call().await.unwrap();
+
+    let configurables = configurables.with_REGISTRY_CONTRACT_ID(registry_id.clone().into()).unwrap();
+    let mut bytecode = unconfigured_bytecode();
+    Configurables::from(configurables.clone()).update_constants_in(&mut bytecode);
+
+    let contract = Contract::load_from(NFT_CONTRACT_BINARY_PATH, LoadConfiguration::default()
+        .with_configurables(configurables)
+        .with_salt(deployment_salt(owner))
+    )
+        .unwrap();
+    let code_root = contract.code_root();
+    let state_root = contract.state_root();
+    let id = contract.deploy(wallet, TxPolicies::default()).await.unwrap();
+
+    // Allowlisting the configured bytecode lets the proof skip the configurables
+    registry
+        .methods()
+        .add_template(Bits256(*code_root), ContractType::MultiEdition, vec![], Some(registry_offset(&bytecode, registry_id.into())))
+        .call()
+        .await
+        .unwrap();
+
+    DEPLOYMENTS.lock().unwrap().push((
+        id.clone().into(),
+        Deployment {
+            bytecode,
+            configurables: None,
+            salt: Bits256(DEPLOYMENT_SALT),
+            state_root: Bits256(*state_root),
+        },
+    ));
+
+    id
+}
+
+// Deploys a multi-edition contract without a registry, whose constructor can never be called
+pub(crate) async fn deploy_without_registry(wallet: &WalletUnlocked) -> Props1155Edition<WalletUnlocked> {
+    let id = Contract::load_from(NFT_CONTRACT_BINARY_PATH, LoadConfiguration::default().with_salt([1u8; 32]))
+        .unwrap()
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+    Props1155Edition::new(id, wallet.clone())
+}
+
+pub(crate) fn get_asset_id(sub_id: Bytes32, contract: ContractId) -> AssetId {
+    let mut hasher = Sha256::new();
+    hasher.update(*contract);
+    hasher.update(*sub_id);
+    AssetId::new(*Bytes32::from(<[u8; 32]>::from(hasher.finalize())))
+}
+
+fn role(name: &str) -> Bits256 {
+    Bits256(<[u8; 32]>::from(Sha256::digest(name.as_bytes())))
+}
+
+pub(crate) fn minter_role() -> Bits256 {
+    role("props.role.minter")
+}
+
+pub(crate) fn metadata_role() -> Bits256 {
+    role("props.role.metadata")
+}
+
+pub(crate) fn finance_role() -> Bits256 {
+    role("props.role.finance")
+}
+
+pub(crate) async fn get_wallet_balance(wallet: &WalletUnlocked, asset: &AssetId) -> u64 {
+    wallet.get_asset_balance(asset).await.unwrap()
+}
+
+pub fn default_metadata_keys() -> Vec<String> {
+    vec![
+        String::from("name"),
+        String::from("image"),
+    ]
+}
+
+pub fn default_metadata_values() -> Vec<Metadata> {
+    vec![
+        Metadata::String(String::from("Props Series #1")),
+        Metadata::String(String::from("https://storage.googleapis.com/opensea-prod.appspot.com/puffs/3.png")),
+    ]
+}
+
+pub fn default_name() -> String {
+    "My Props Series".to_string()
+}
+
+pub fn default_symbol() -> String {
+    "PSER".to_string()
+}
+
+pub fn default_price() -> u64 {
+    0
+}
+
+pub fn default_max_supply() -> u64 {
+    10
+}
+
+pub fn default_start_date() -> u64 {
+    // TAI64 timestamp for 1970-01-01 00:00:00 UTC (Unix epoch)
+    // 4611686018427387904 (decimal) = 0x4000000000000000 (hex)
+    4611686018427387904
+}
+
+pub fn default_end_date() -> u64 {
+    // TAI64 timestamp for 2050-01-01 00:00:00 UTC
+    // This is approximately 80 years after the Unix epoch
+    // 4643769087344304128 (decimal) = 0x4061A1CAC0000000 (hex)
+    4643769087344304128
+}
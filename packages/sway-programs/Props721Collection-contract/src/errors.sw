@@ -11,8 +11,25 @@ pub enum MintError {
     OutsideMintingPeriod: String,
     InvalidProof: (),
     ExceededMaxMintLimit: (),
+    ExceededMaxPerTransaction: (),
+    MintCooldownActive: (),
+    ExceededMaxMintsPerBlock: (),
+    ProtocolPaused: (),
+    InvalidAmount: (),
 }
 
 pub enum SetError {
     ValueAlreadySet: (),
+    MetadataFrozen: (),
+    InvalidDecimals: (),
+    InvalidMaxSupply: (),
+    InvalidMintWindow: (),
+    SupplyLocked: (),
+    InvalidFeePercentages: (),
+}
+
+pub enum AttributeError {
+    LengthMismatch: (),
+    InvalidAttributeType: (),
+    AssetNotMinted: (),
 }
@@ -3,6 +3,7 @@ library;
 use std::string::String;
 use std::vec::Vec;
 use standards::src7::Metadata;
+use libraries::MintQuote;
 
 abi SetMintMetadata {
     #[storage(write)]
@@ -14,7 +15,11 @@ abi SetMintMetadata {
     #[storage(read)]
     fn total_price() -> Option<u64>;
 
+    #[storage(read)]
     fn fees() -> Option<(u64, u64)>;
+
+    #[storage(read)]
+    fn quote_mint(recipient: Identity, amount: u64, affiliate: Option<Identity>) -> MintQuote;
 }
 
 abi SetTokenUri {
@@ -0,0 +1,78 @@
+use crate::utils::{
+    interface::{constructor, freeze_metadata, metadata_frozen},
+    setup::{defaults, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn freezes_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert!(!metadata_frozen(&instance_1).await);
+
+        freeze_metadata(&instance_1).await;
+
+        assert!(metadata_frozen(&instance_1).await);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        freeze_metadata(&instance_2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MetadataFrozen")]
+    async fn when_already_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        freeze_metadata(&instance_1).await;
+
+        freeze_metadata(&instance_1).await;
+    }
+}
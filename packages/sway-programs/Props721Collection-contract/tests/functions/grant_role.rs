@@ -0,0 +1,148 @@
+use crate::utils::{
+    interface::{airdrop, constructor, grant_role, has_role, is_paused, pause, set_price, price},
+    setup::{defaults, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri, finance_role, get_wallet_balance, metadata_role, minter_role, pauser_role},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn grants_role() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert!(!has_role(&instance_1, minter_role(), other_identity).await);
+
+        let response = grant_role(&instance_1, minter_role(), other_identity).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("RoleGrantedEvent")));
+
+        assert!(has_role(&instance_1, minter_role(), other_identity).await);
+        assert!(!has_role(&instance_1, pauser_role(), other_identity).await);
+        assert!(!has_role(&instance_1, metadata_role(), other_identity).await);
+    }
+
+    #[tokio::test]
+    async fn minter_can_airdrop() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, minter_role(), other_identity).await;
+
+        airdrop(&instance_2, other_identity, 1).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 1);
+    }
+
+    #[tokio::test]
+    async fn pauser_can_pause() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, pauser_role(), other_identity).await;
+
+        pause(&instance_2).await;
+
+        assert!(is_paused(&instance_1).await);
+    }
+
+    #[tokio::test]
+    async fn finance_can_set_price() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, finance_role(), other_identity).await;
+
+        set_price(&instance_2, 1_000).await;
+
+        assert_eq!(price(&instance_1).await, Some(1_000));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        grant_role(&instance_2, minter_role(), other_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_role_does_not_match() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, minter_role(), other_identity).await;
+
+        pause(&instance_2).await;
+    }
+}
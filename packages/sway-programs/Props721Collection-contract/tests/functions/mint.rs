@@ -1,6 +1,6 @@
 use crate::utils::{
-    interface::{burn, constructor, mint, pause, total_assets, total_supply, set_fee, fee, fee_constructor, set_price, set_merkle_root},
-    setup::{defaults, default_start_date, default_end_date,get_wallet_balance, setup, deploy_collection_with_builder_fee, default_name, default_symbol, default_price, default_base_uri},
+    interface::{burn, constructor, mint, pause, total_assets, total_supply, set_fee, set_fee_contract, fee, fee_constructor, set_price, set_merkle_root},
+    setup::{defaults, default_start_date, default_end_date,get_wallet_balance, registry_contract_id, setup, deploy_collection_with_builder_fee, deploy_fee_splitter, default_name, default_symbol, default_price, default_base_uri, PropsRegistry},
 };
 use fuels::{
     prelude::*,
@@ -527,6 +527,73 @@ mod success {
         assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(1));
         assert_eq!(total_assets(&instance_1).await, 3);
     }
+
+    #[tokio::test]
+    async fn mints_without_start_and_end_dates() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), 0, 0).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 1);
+        assert_eq!(total_assets(&instance_1).await, 1);
+    }
+
+    #[tokio::test]
+    async fn pays_fee_to_registry_fee_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        let protocol_fee_instance = deploy_fee_splitter(&owner_wallet, 2).await;
+        let protocol_fee_id = ContractId::from(protocol_fee_instance.contract_id());
+        fee_constructor(&protocol_fee_instance, owner_identity).await;
+        set_fee(&protocol_fee_instance, 1_000).await;
+
+        let registry = PropsRegistry::new(registry_contract_id(), owner_wallet);
+        set_fee_contract(&registry, Some(protocol_fee_id)).await;
+
+        // The fee contract the collection was deployed with is no longer called
+        instance_2
+            .methods()
+            .mint(other_identity, sub_id_1, 1, None, None, None, None, None)
+            .with_contract_ids(&[protocol_fee_id.into(), registry_contract_id().into()])
+            .append_variable_outputs(4)
+            .call_params(CallParameters::new(1_000, AssetId::zeroed(), 1_000_000))
+            .unwrap()
+            .call()
+            .await
+            .unwrap();
+
+        let fee_contract_balances = protocol_fee_instance.get_balances().await.unwrap();
+        assert_eq!(fee_contract_balances.get(&AssetId::zeroed()).copied().unwrap_or(0), 1_000);
+
+        let default_fee_contract_balances = fee_instance_1.get_balances().await.unwrap();
+        assert_eq!(default_fee_contract_balances.get(&AssetId::zeroed()).copied().unwrap_or(0), 0);
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 1);
+    }
 }
 
 mod revert {
@@ -555,6 +622,29 @@ mod revert {
         mint(&instance_2, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
     }
 
+    #[tokio::test]
+    #[should_panic(expected = "ProtocolPaused")]
+    async fn when_protocol_paused() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet);
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        let registry = PropsRegistry::new(registry_contract_id(), owner_wallet);
+        registry.methods().set_protocol_paused(true).call().await.unwrap();
+
+        mint(&instance_2, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+    }
+
     #[tokio::test]
     #[should_panic(expected = "MaxNFTsMinted")]
     async fn when_max_supply_reached() {
@@ -0,0 +1,151 @@
+use crate::utils::{
+    interface::{constructor, mint, quote_mint, set_price, total_assets},
+    setup::{defaults, get_wallet_balance, deploy_collection_with_builder_fee, MintQuote, default_name, default_base_uri, default_symbol, default_price, default_end_date, default_start_date},
+};
+
+use fuels::{
+    prelude::*,
+    types::{AssetId, Identity},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn quotes_builder_fee_for_amount() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2, fee_id, _fee_instance_1) = deploy_collection_with_builder_fee(Some(0)).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        set_price(&instance_1, 1_000).await;
+
+        let quote = quote_mint(&instance_1, other_identity, 2, None).await;
+        assert_eq!(
+            quote,
+            MintQuote { creator_price: 2_000, protocol_fee: 0, builder_fee: 1_000, builder_revenue_share: 0, affiliate_fee: 0, total: 3_000 }
+        );
+
+        let initial_another_wallet_balance = get_wallet_balance(&another_wallet, &AssetId::zeroed()).await;
+
+        // Forwarding the quoted total is exactly enough to mint
+        mint(&instance_2, other_identity, sub_id_1, 2, quote.total, fee_id, None, None, None, None, None).await;
+
+        assert_eq!(get_wallet_balance(&another_wallet, &AssetId::zeroed()).await, initial_another_wallet_balance + quote.builder_fee);
+        assert_eq!(total_assets(&instance_1).await, 2);
+    }
+
+    #[tokio::test]
+    async fn quotes_builder_revenue_share() {
+        let (owner_wallet, other_wallet, _another_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = deploy_collection_with_builder_fee(Some(1)).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        set_price(&instance_1, 1_000).await;
+
+        // The revenue share is taken from the price of every token minted
+        let quote = quote_mint(&instance_1, other_identity, 2, None).await;
+        assert_eq!(
+            quote,
+            MintQuote { creator_price: 1_000, protocol_fee: 0, builder_fee: 0, builder_revenue_share: 1_000, affiliate_fee: 0, total: 2_000 }
+        );
+    }
+
+    #[tokio::test]
+    async fn quotes_affiliate_fee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = deploy_collection_with_builder_fee(Some(2)).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        set_price(&instance_1, 1_000).await;
+
+        let affiliate = Identity::Address(another_wallet.address().into());
+
+        let quote = quote_mint(&instance_1, other_identity, 1, Some(affiliate)).await;
+        assert_eq!(
+            quote,
+            MintQuote { creator_price: 900, protocol_fee: 0, builder_fee: 0, builder_revenue_share: 0, affiliate_fee: 100, total: 1_000 }
+        );
+
+        // Only address affiliates are paid
+        let quote = quote_mint(&instance_1, other_identity, 1, Some(Identity::ContractId(id))).await;
+        assert_eq!(quote.affiliate_fee, 0);
+        assert_eq!(quote.creator_price, 1_000);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotEnoughTokens")]
+    async fn when_paying_less_than_quote() {
+        let (owner_wallet, other_wallet, _another_wallet, id, instance_1, instance_2, fee_id, _fee_instance_1) = deploy_collection_with_builder_fee(Some(0)).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        set_price(&instance_1, 1_000).await;
+
+        let quote = quote_mint(&instance_1, other_identity, 1, None).await;
+
+        mint(&instance_2, other_identity, sub_id_1, 1, quote.total - 1, fee_id, None, None, None, None, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidAmount")]
+    async fn when_amount_is_zero() {
+        let (owner_wallet, other_wallet, _another_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = deploy_collection_with_builder_fee(Some(0)).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        set_price(&instance_1, 1_000).await;
+
+        quote_mint(&instance_1, other_identity, 0, None).await;
+    }
+}
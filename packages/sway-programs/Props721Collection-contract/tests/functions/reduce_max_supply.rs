@@ -0,0 +1,163 @@
+use crate::utils::{
+    interface::{airdrop, constructor, lock_supply, max_supply, reduce_max_supply},
+    setup::{defaults, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reduces_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        reduce_max_supply(&instance_1, 2).await;
+
+        assert_eq!(max_supply(&instance_1).await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn reduces_max_supply_to_total_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        reduce_max_supply(&instance_1, 1).await;
+
+        assert_eq!(max_supply(&instance_1).await, Some(1));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        reduce_max_supply(&instance_2, 2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidMaxSupply")]
+    async fn when_below_total_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        reduce_max_supply(&instance_1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidMaxSupply")]
+    async fn when_not_lower_than_current_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        reduce_max_supply(&instance_1, 3).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "SupplyLocked")]
+    async fn when_supply_locked() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        lock_supply(&instance_1).await;
+
+        reduce_max_supply(&instance_1, 2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxNFTsMinted")]
+    async fn when_minting_past_reduced_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        reduce_max_supply(&instance_1, 1).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        airdrop(&instance_1, other_identity, 1).await;
+    }
+}
@@ -0,0 +1,81 @@
+use crate::utils::{
+    interface::{airdrop, constructor, grant_role, has_role, revoke_role},
+    setup::{defaults, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri, minter_role},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn revokes_role() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, minter_role(), other_identity).await;
+
+        let response = revoke_role(&instance_1, minter_role(), other_identity).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("RoleRevokedEvent")));
+
+        assert!(!has_role(&instance_1, minter_role(), other_identity).await);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, minter_role(), other_identity).await;
+
+        revoke_role(&instance_2, minter_role(), other_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_revoked_minter_airdrops() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, minter_role(), other_identity).await;
+        revoke_role(&instance_1, minter_role(), other_identity).await;
+
+        airdrop(&instance_2, other_identity, 1).await;
+    }
+}
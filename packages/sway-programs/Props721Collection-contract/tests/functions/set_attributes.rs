@@ -0,0 +1,219 @@
+use crate::utils::{
+    interface::{airdrop, attributes, constructor, grant_role, metadata, set_attributes},
+    setup::{defaults, metadata_role, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri, Metadata},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_attributes() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        set_attributes(
+            &instance_1,
+            asset_id_1,
+            vec![String::from("class"), String::from("level")],
+            vec![Metadata::String(String::from("Warrior")), Metadata::Int(5)],
+        ).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("attr:class")).await,
+            Some(Metadata::String(String::from("Warrior")))
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("attr:level")).await,
+            Some(Metadata::Int(5))
+        );
+        assert_eq!(attributes(&instance_1, asset_id_1, 0, 10).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn overwrites_existing_attribute() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        set_attributes(&instance_1, asset_id_1, vec![String::from("level")], vec![Metadata::Int(5)]).await;
+        set_attributes(&instance_1, asset_id_1, vec![String::from("level")], vec![Metadata::Int(6)]).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("attr:level")).await,
+            Some(Metadata::Int(6))
+        );
+        assert_eq!(
+            attributes(&instance_1, asset_id_1, 0, 10).await,
+            vec![(String::from("level"), Metadata::Int(6))]
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_attributes_per_asset() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        set_attributes(&instance_1, asset_id_1, vec![String::from("level")], vec![Metadata::Int(1)]).await;
+        set_attributes(&instance_1, asset_id_2, vec![String::from("level")], vec![Metadata::Int(2)]).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("attr:level")).await,
+            Some(Metadata::Int(1))
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, String::from("attr:level")).await,
+            Some(Metadata::Int(2))
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_attributes_with_metadata_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+        grant_role(&instance_1, metadata_role(), other_identity).await;
+
+        set_attributes(&instance_2, asset_id_1, vec![String::from("level")], vec![Metadata::Int(5)]).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("attr:level")).await,
+            Some(Metadata::Int(5))
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_missing_metadata_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        set_attributes(&instance_2, asset_id_1, vec![String::from("level")], vec![Metadata::Int(5)]).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "LengthMismatch")]
+    async fn when_length_mismatch() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        set_attributes(&instance_1, asset_id_1, vec![String::from("level"), String::from("class")], vec![Metadata::Int(5)]).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidAttributeType")]
+    async fn when_invalid_type() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        set_attributes(&instance_1, asset_id_1, vec![String::from("level")], vec![Metadata::B256(fuels::types::Bits256([1u8; 32]))]).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AssetNotMinted")]
+    async fn when_asset_not_minted() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        set_attributes(&instance_1, asset_id_1, vec![String::from("level")], vec![Metadata::Int(5)]).await;
+    }
+}
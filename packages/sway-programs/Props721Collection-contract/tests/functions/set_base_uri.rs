@@ -1,5 +1,5 @@
 use crate::utils::{
-    interface::{constructor, base_uri, set_base_uri},
+    interface::{constructor, base_uri, freeze_metadata, set_base_uri},
     setup::{defaults, default_start_date, default_end_date,setup, default_name, default_price, default_base_uri, default_symbol},
 };
 
@@ -31,3 +31,29 @@ mod success {
         assert_eq!(base_uri(&instance_1).await, Some(String::from("new_base_uri")));
     }
 }
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MetadataFrozen")]
+    async fn when_metadata_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        freeze_metadata(&instance_1).await;
+
+        set_base_uri(&instance_1, String::from("new_base_uri")).await;
+    }
+}
@@ -1,6 +1,6 @@
 use crate::utils::{
-    interface::{constructor, start_date, end_date, set_dates},
-    setup::{defaults, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri},
+    interface::{constructor, grant_role, start_date, end_date, set_dates},
+    setup::{defaults, metadata_role, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri},
 };
 
 mod success {
@@ -79,6 +79,54 @@ mod success {
         assert_eq!(start_date(&instance_1).await, Some(default_start_date()));
         assert_eq!(end_date(&instance_1).await, Some(new_end_date));
     }
+
+    #[tokio::test]
+    async fn clears_start_and_end_dates() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        set_dates(&instance_1, 0, 0).await;
+
+        assert_eq!(start_date(&instance_1).await, None);
+        assert_eq!(end_date(&instance_1).await, None);
+    }
+
+    #[tokio::test]
+    async fn updates_dates_with_metadata_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, metadata_role(), other_identity).await;
+
+        let new_start_date = default_start_date() + 86400;
+        let new_end_date = default_end_date() + 172800;
+
+        set_dates(&instance_2, new_start_date, new_end_date).await;
+
+        assert_eq!(start_date(&instance_1).await, Some(new_start_date));
+        assert_eq!(end_date(&instance_1).await, Some(new_end_date));
+    }
 }
 
 mod revert {
@@ -86,8 +134,8 @@ mod revert {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "NotOwner")]
-    async fn when_not_owner() {
+    #[should_panic(expected = "MissingRole")]
+    async fn when_missing_metadata_role() {
         let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
         let (
             _asset_id_1,
@@ -108,4 +156,24 @@ mod revert {
         // This should panic because instance_2 is not the owner
         set_dates(&instance_2, new_start_date, new_end_date).await;
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidMintWindow")]
+    async fn when_start_date_is_not_before_end_date() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        set_dates(&instance_1, default_end_date(), default_start_date()).await;
+    }
+}
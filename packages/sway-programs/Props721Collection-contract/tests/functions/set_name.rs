@@ -0,0 +1,102 @@
+use crate::utils::{
+    interface::{constructor, grant_role, freeze_metadata, name, set_name},
+    setup::{defaults, metadata_role, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_name() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(name(&instance_1, asset_id_1).await, Some(default_name()));
+
+        let response = set_name(&instance_1, asset_id_1, String::from("My Renamed NFT")).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("SetNameEvent")));
+
+        assert_eq!(name(&instance_1, asset_id_1).await, Some(String::from("My Renamed NFT")));
+    }
+
+    #[tokio::test]
+    async fn sets_name_with_metadata_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        grant_role(&instance_1, metadata_role(), other_identity).await;
+
+        set_name(&instance_2, asset_id_1, String::from("My Renamed NFT")).await;
+
+        assert_eq!(name(&instance_1, asset_id_1).await, Some(String::from("My Renamed NFT")));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_missing_metadata_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        set_name(&instance_2, asset_id_1, String::from("My Renamed NFT")).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MetadataFrozen")]
+    async fn when_metadata_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        freeze_metadata(&instance_1).await;
+
+        set_name(&instance_1, asset_id_1, String::from("My Renamed NFT")).await;
+    }
+}
@@ -0,0 +1,111 @@
+use crate::utils::{
+    interface::{airdrop, constructor, sweep, sweepable, transfer_to_contract},
+    setup::{defaults, setup, get_wallet_balance, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri},
+};
+
+use fuels::types::AssetId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sweeps_returned_nfts() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone(), default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, owner_identity.clone(), 1).await;
+        transfer_to_contract(&owner_wallet, &instance_1, 1, asset_id_1).await;
+
+        assert_eq!(sweepable(&instance_1, asset_id_1).await, 1);
+
+        sweep(&instance_1, asset_id_1, 1, other_identity).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 1);
+        assert_eq!(sweepable(&instance_1, asset_id_1).await, 0);
+    }
+
+    #[tokio::test]
+    async fn sweeps_base_asset() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone(), default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        transfer_to_contract(&owner_wallet, &instance_1, 1_000, AssetId::zeroed()).await;
+
+        let initial_other_balance = get_wallet_balance(&other_wallet, &AssetId::zeroed()).await;
+
+        let response = sweep(&instance_1, AssetId::zeroed(), 400, other_identity).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("FundsSweptEvent")));
+
+        assert_eq!(get_wallet_balance(&other_wallet, &AssetId::zeroed()).await, initial_other_balance + 400);
+        assert_eq!(sweepable(&instance_1, AssetId::zeroed()).await, 600);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone(), default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        transfer_to_contract(&owner_wallet, &instance_1, 1_000, AssetId::zeroed()).await;
+
+        sweep(&instance_2, AssetId::zeroed(), 1_000, other_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ExceedsSweepableBalance")]
+    async fn when_amount_exceeds_balance() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone(), default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+        transfer_to_contract(&owner_wallet, &instance_1, 1_000, AssetId::zeroed()).await;
+
+        sweep(&instance_1, AssetId::zeroed(), 1_001, other_identity).await;
+    }
+}
@@ -0,0 +1,80 @@
+use crate::utils::{
+    interface::{constructor, owner, pending_owner, transfer_ownership},
+    setup::{defaults, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_base_uri, State},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn proposes_new_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(pending_owner(&instance_1).await, None);
+
+        transfer_ownership(&instance_1, other_identity).await;
+
+        assert_eq!(pending_owner(&instance_1).await, Some(other_identity));
+        assert_eq!(owner(&instance_1).await, State::Initialized(owner_identity));
+    }
+
+    #[tokio::test]
+    async fn replaces_pending_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        transfer_ownership(&instance_1, other_identity).await;
+        transfer_ownership(&instance_1, owner_identity).await;
+
+        assert_eq!(pending_owner(&instance_1).await, Some(owner_identity));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_base_uri(), default_price(), default_start_date(), default_end_date()).await;
+
+        transfer_ownership(&instance_2, other_identity).await;
+    }
+}
@@ -1,5 +1,7 @@
-use crate::utils::setup::{Metadata, State, Props721Collection, PropsFeeSplitter};
+use crate::utils::setup::{deployment, registry_contract_id, Metadata, MintQuote, State, Props721Collection, PropsFeeSplitter, PropsRegistry};
+use chrono::{Duration, Utc};
 use fuels::{
+    accounts::{Account, ViewOnlyAccount},
     prelude::{AssetId, CallParameters, TxPolicies, WalletUnlocked, ContractId, Bech32ContractId},
     programs::{call_response::FuelCallResponse, call_utils::TxDependencyExtension},
     types::{Bits256, Identity},
@@ -65,7 +67,7 @@ pub(crate) async fn mint(
     contract
         .methods()
         .mint(recipient, sub_id, amount, affilate, proof, key, num_leaves, max_amount)
-        .with_contract_ids(&[id.clone()])
+        .with_contract_ids(&[id.clone(), registry_contract_id().into()])
         .append_variable_outputs(4)
         .call_params(CallParameters::new(price, AssetId::zeroed(), 1_000_000))
         .unwrap()
@@ -74,6 +76,27 @@ pub(crate) async fn mint(
         .unwrap()
 }
 
+pub(crate) async fn quote_mint(
+    contract: &Props721Collection<WalletUnlocked>,
+    recipient: Identity,
+    amount: u64,
+    affiliate: Option<Identity>,
+) -> MintQuote {
+    // @dev TODO: This is a hack to get the contract id, should be refactored
+    let id = Bech32ContractId::from(
+        ContractId::from_str("0xd65987a6b981810a28559d57e5083d47a10ce269cbf96316554d5b4a1b78485a")
+        .unwrap(),
+    );
+    contract
+        .methods()
+        .quote_mint(recipient, amount, affiliate)
+        .with_contract_ids(&[id.clone(), registry_contract_id().into()])
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
 pub(crate) async fn airdrop(
     contract: &Props721Collection<WalletUnlocked>,
     recipient: Identity,
@@ -121,12 +144,16 @@ pub(crate) async fn constructor(
     start_date: u64,
     end_date: u64,
 ) -> FuelCallResponse<()> {
-    // let id = Bech32ContractId::from(
-    //     ContractId::from_str("0x9b48042cdc01cf86a7d9d5f47b3ea43898c7d7a8282eede5f2f0c219d2e7c93f")
-    //     .unwrap(),
-    // );
-    let resp = contract.methods().constructor(owner, name, symbol, base_uri, price, start_date, end_date).call().await.unwrap();
-    resp
+    // The collection only accepts its constructor from the registry, so initialize it through `init_collection`
+    let contract_id = ContractId::from(contract.contract_id());
+    let registry = PropsRegistry::new(registry_contract_id(), contract.account());
+    registry
+        .methods()
+        .init_collection(contract_id, owner, name, symbol, base_uri, price, start_date, end_date, deployment(contract_id))
+        .with_contracts(&[contract])
+        .call()
+        .await
+        .unwrap()
 }
 
 pub(crate) async fn metadata(
@@ -191,12 +218,45 @@ pub(crate) async fn set_fee(
     contract: &PropsFeeSplitter<WalletUnlocked>,
     fee: u64,
 ) -> FuelCallResponse<()> {
-    contract
+    let response = contract
         .methods()
         .set_fee(fee)
         .call()
         .await
-        .unwrap()
+        .unwrap();
+
+    // Fee increases are timelocked, so move the chain past the delay and apply them
+    let pending = contract.methods().pending_fee(AssetId::zeroed()).call().await.unwrap().value;
+    if pending.is_some() {
+        let provider = contract.account().try_provider().unwrap().clone();
+        provider
+            .produce_blocks(1, Some(Utc::now() + Duration::days(8)))
+            .await
+            .unwrap();
+
+        contract.methods().apply_fee(AssetId::zeroed()).call().await.unwrap();
+    }
+
+    response
+}
+
+pub(crate) async fn set_fee_contract(
+    registry: &PropsRegistry<WalletUnlocked>,
+    fee_contract: Option<ContractId>,
+) -> FuelCallResponse<()> {
+    let response = registry.methods().set_fee_contract(fee_contract).call().await.unwrap();
+
+    // Fee contract changes are timelocked, so move the chain past the delay and apply them
+    let provider = registry.account().try_provider().unwrap().clone();
+    let now = provider.latest_block_time().await.unwrap().unwrap_or_else(Utc::now);
+    provider
+        .produce_blocks(1, Some(now + Duration::days(8)))
+        .await
+        .unwrap();
+
+    registry.methods().apply_fee_contract().call().await.unwrap();
+
+    response
 }
 
 pub(crate) async fn fee(contract: &PropsFeeSplitter<WalletUnlocked>) -> Option<u64> {
@@ -244,4 +304,177 @@ pub(crate) async fn set_merkle_root(
 
 pub(crate) async fn max_supply(contract: &Props721Collection<WalletUnlocked>) -> Option<u64> {
     contract.methods().max_supply().call().await.unwrap().value
-}
\ No newline at end of file
+}
+
+pub(crate) async fn set_attributes(
+    contract: &Props721Collection<WalletUnlocked>,
+    asset: AssetId,
+    keys: Vec<String>,
+    values: Vec<Metadata>,
+) -> FuelCallResponse<()> {
+    contract
+        .methods()
+        .set_attributes(asset, keys, values)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn attributes(
+    contract: &Props721Collection<WalletUnlocked>,
+    asset: AssetId,
+    offset: u64,
+    limit: u64,
+) -> Vec<(String, Metadata)> {
+    contract
+        .methods()
+        .attributes(asset, offset, limit)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn set_name(
+    contract: &Props721Collection<WalletUnlocked>,
+    asset: AssetId,
+    name: String,
+) -> FuelCallResponse<()> {
+    contract.methods().set_name(asset, name).call().await.unwrap()
+}
+
+pub(crate) async fn set_symbol(
+    contract: &Props721Collection<WalletUnlocked>,
+    asset: AssetId,
+    symbol: String,
+) -> FuelCallResponse<()> {
+    contract.methods().set_symbol(asset, symbol).call().await.unwrap()
+}
+
+pub(crate) async fn freeze_metadata(contract: &Props721Collection<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().freeze_metadata().call().await.unwrap()
+}
+
+pub(crate) async fn metadata_frozen(contract: &Props721Collection<WalletUnlocked>) -> bool {
+    contract.methods().metadata_frozen().call().await.unwrap().value
+}
+
+pub(crate) async fn max_per_transaction(contract: &Props721Collection<WalletUnlocked>) -> Option<u64> {
+    contract.methods().max_per_transaction().call().await.unwrap().value
+}
+
+pub(crate) async fn mint_cooldown(contract: &Props721Collection<WalletUnlocked>) -> Option<u64> {
+    contract.methods().mint_cooldown().call().await.unwrap().value
+}
+
+pub(crate) async fn mint_cooldown_blocks(contract: &Props721Collection<WalletUnlocked>) -> Option<u32> {
+    contract.methods().mint_cooldown_blocks().call().await.unwrap().value
+}
+
+pub(crate) async fn max_mints_per_block(contract: &Props721Collection<WalletUnlocked>) -> Option<u64> {
+    contract.methods().max_mints_per_block().call().await.unwrap().value
+}
+
+pub(crate) async fn last_mint_time(
+    contract: &Props721Collection<WalletUnlocked>,
+    wallet: Identity,
+) -> Option<u64> {
+    contract.methods().last_mint_time(wallet).call().await.unwrap().value
+}
+
+pub(crate) async fn last_mint_height(
+    contract: &Props721Collection<WalletUnlocked>,
+    wallet: Identity,
+) -> Option<u32> {
+    contract.methods().last_mint_height(wallet).call().await.unwrap().value
+}
+
+pub(crate) async fn reduce_max_supply(
+    contract: &Props721Collection<WalletUnlocked>,
+    new_max_supply: u64,
+) -> FuelCallResponse<()> {
+    contract.methods().reduce_max_supply(new_max_supply).call().await.unwrap()
+}
+
+pub(crate) async fn lock_supply(contract: &Props721Collection<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().lock_supply().call().await.unwrap()
+}
+
+pub(crate) async fn supply_locked(contract: &Props721Collection<WalletUnlocked>) -> bool {
+    contract.methods().supply_locked().call().await.unwrap().value
+}
+
+pub(crate) async fn transfer_ownership(
+    contract: &Props721Collection<WalletUnlocked>,
+    new_owner: Identity,
+) -> FuelCallResponse<()> {
+    contract.methods().transfer_ownership(new_owner).call().await.unwrap()
+}
+
+pub(crate) async fn accept_ownership(contract: &Props721Collection<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().accept_ownership().call().await.unwrap()
+}
+
+pub(crate) async fn renounce_ownership(contract: &Props721Collection<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().renounce_ownership().call().await.unwrap()
+}
+
+pub(crate) async fn pending_owner(contract: &Props721Collection<WalletUnlocked>) -> Option<Identity> {
+    contract.methods().pending_owner().call().await.unwrap().value
+}
+
+pub(crate) async fn grant_role(
+    contract: &Props721Collection<WalletUnlocked>,
+    role: Bits256,
+    account: Identity,
+) -> FuelCallResponse<()> {
+    contract.methods().grant_role(role, account).call().await.unwrap()
+}
+
+pub(crate) async fn revoke_role(
+    contract: &Props721Collection<WalletUnlocked>,
+    role: Bits256,
+    account: Identity,
+) -> FuelCallResponse<()> {
+    contract.methods().revoke_role(role, account).call().await.unwrap()
+}
+
+pub(crate) async fn has_role(
+    contract: &Props721Collection<WalletUnlocked>,
+    role: Bits256,
+    account: Identity,
+) -> bool {
+    contract.methods().has_role(role, account).call().await.unwrap().value
+}
+
+pub(crate) async fn sweep(
+    contract: &Props721Collection<WalletUnlocked>,
+    asset: AssetId,
+    amount: u64,
+    recipient: Identity,
+) -> FuelCallResponse<()> {
+    contract
+        .methods()
+        .sweep(asset, amount, recipient)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn sweepable(contract: &Props721Collection<WalletUnlocked>, asset: AssetId) -> u64 {
+    contract.methods().sweepable(asset).call().await.unwrap().value
+}
+
+// Sends assets to the contract without calling any of its functions
+pub(crate) async fn transfer_to_contract(
+    wallet: &WalletUnlocked,
+    contract: &Props721Collection<WalletUnlocked>,
+    amount: u64,
+    asset: AssetId,
+) {
+    wallet
+        .force_transfer_to_contract(contract.contract_id(), amount, asset, TxPolicies::default())
+        .await
+        .unwrap();
+}
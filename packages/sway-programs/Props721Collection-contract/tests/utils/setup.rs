@@ -1,12 +1,14 @@
 use fuels::{
     accounts::ViewOnlyAccount,
+    core::Configurables,
     prelude::{
-        abigen, launch_custom_provider_and_get_wallets, AssetConfig, Contract, ContractId,
-        LoadConfiguration, TxPolicies, WalletUnlocked, WalletsConfig,
+        abigen, launch_custom_provider_and_get_wallets, AssetConfig, Bech32ContractId, Contract,
+        ContractId, LoadConfiguration, TxPolicies, WalletUnlocked, WalletsConfig,
     },
     types::{Address, AssetId, Bits256, Bytes32, Identity},
 };
 use sha2::{Digest, Sha256};
+use std::sync::Mutex;
 
 abigen!(Contract(
     name = "Props721Collection",
@@ -25,6 +27,12 @@ const NFT_CONTRACT_BINARY_PATH: &str = "./out/debug/Props721Collection-contract.
 
 const REGISTRY_CONTRACT_BINARY_PATH: &str = "../PropsRegistry-contract/out/debug/PropsRegistry-contract.bin";
 
+// The value every test deployment salt is derived from, together with its owner
+const DEPLOYMENT_SALT: [u8; 32] = [7u8; 32];
+
+// The deployment proofs of the collections deployed by `setup`, handed to the registry on `constructor`
+static DEPLOYMENTS: Mutex<Vec<(ContractId, Deployment)>> = Mutex::new(Vec::new());
+
 
 pub(crate) fn defaults(
     contract_id: ContractId,
@@ -75,6 +83,160 @@ pub(crate) async fn setup() -> (
     Props721Collection<WalletUnlocked>,
     ContractId,
     PropsFeeSplitter<WalletUnlocked>,
+) {
+    setup_with_configurables(Props721CollectionConfigurables::default()).await
+}
+
+pub(crate) async fn deploy_with_mint_limits(
+    max_per_transaction: u64,
+    mint_cooldown: u64,
+    max_mints_per_block: u64,
+) -> (
+    WalletUnlocked,
+    WalletUnlocked,
+    ContractId,
+    Props721Collection<WalletUnlocked>,
+    Props721Collection<WalletUnlocked>,
+    ContractId,
+    PropsFeeSplitter<WalletUnlocked>,
+) {
+    let configurables = Props721CollectionConfigurables::default()
+        .with_MAX_PER_TRANSACTION(max_per_transaction).unwrap()
+        .with_MINT_COOLDOWN(mint_cooldown).unwrap()
+        .with_MAX_MINTS_PER_BLOCK(max_mints_per_block).unwrap();
+
+    setup_with_configurables(configurables).await
+}
+
+pub(crate) async fn deploy_with_mint_cooldown_blocks(
+    mint_cooldown_blocks: u32,
+) -> (
+    WalletUnlocked,
+    WalletUnlocked,
+    ContractId,
+    Props721Collection<WalletUnlocked>,
+    Props721Collection<WalletUnlocked>,
+    ContractId,
+    PropsFeeSplitter<WalletUnlocked>,
+) {
+    let configurables = Props721CollectionConfigurables::default()
+        .with_MINT_COOLDOWN_BLOCKS(mint_cooldown_blocks).unwrap();
+
+    setup_with_configurables(configurables).await
+}
+
+// The registry deployed by `setup` always lands at the same id for a given binary
+pub(crate) fn registry_contract_id() -> ContractId {
+    Contract::load_from(REGISTRY_CONTRACT_BINARY_PATH, LoadConfiguration::default())
+        .unwrap()
+        .contract_id()
+}
+
+// Deploys a fee splitter besides the one `setup` deploys, for tests that point the registry elsewhere
+pub(crate) async fn deploy_fee_splitter(wallet: &WalletUnlocked, salt: u8) -> PropsFeeSplitter<WalletUnlocked> {
+    let id = Contract::load_from(FEE_SPLITTER_CONTRACT_BINARY_PATH, LoadConfiguration::default().with_salt([salt; 32]))
+        .unwrap()
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+    PropsFeeSplitter::new(id, wallet.clone())
+}
+
+pub(crate) fn deployment(contract_id: ContractId) -> Deployment {
+    DEPLOYMENTS
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(id, _)| *id == contract_id)
+        .map(|(_, deployment)| deployment.clone())
+        .unwrap()
+}
+
+// The byte offset of the `REGISTRY_CONTRACT_ID` configurable in configured `bytecode`
+pub(crate) fn registry_offset(bytecode: &[u8], registry_id: ContractId) -> u64 {
+    bytecode.windows(32).position(|window| window == *registry_id).unwrap() as u64
+}
+
+fn deployment_salt(owner: Identity) -> [u8; 32] {
+    // The registry only initializes deployments whose salt is derived from the owner
+    let owner_bits = match owner {
+        Identity::Address(address) => *address,
+        Identity::ContractId(contract_id) => *contract_id,
+    };
+    let mut hasher = Sha256::new();
+    hasher.update(owner_bits);
+    hasher.update(DEPLOYMENT_SALT);
+    <[u8; 32]>::from(hasher.finalize())
+}
+
+// Deploys the registry and a collection that only it can initialize, owned by `wallet`
+async fn deploy_collection(wallet: &WalletUnlocked, configurables: Props721CollectionConfigurables) -> Bech32ContractId {
+    let registry_id = Contract::load_from(REGISTRY_CONTRACT_BINARY_PATH, LoadConfiguration::default())
+        .unwrap()
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+    println!("registry_id hash: {:?}", registry_id.hash());
+
+    let owner = Identity::Address(Address::from(wallet.address()));
+    let registry = PropsRegistry::new(registry_id.clone(), wallet.clone());
+    registry.methods().constructor(owner).call().await.unwrap();
+
+    let configurables = configurables.with_REGISTRY_CONTRACT_ID(registry_id.clone().into()).unwrap();
+    let mut bytecode = std::fs::read(NFT_CONTRACT_BINARY_PATH).unwrap();
+    Configurables::from(configurables.clone()).update_constants_in(&mut bytecode);
+
+    let contract = Contract::load_from(NFT_CONTRACT_BINARY_PATH, LoadConfiguration::default()
+        .with_configurables(configurables)
+        .with_salt(deployment_salt(owner))
+    )
+        .unwrap();
+    let code_root = contract.code_root();
+    let state_root = contract.state_root();
+    let id = contract.deploy(wallet, TxPolicies::default()).await.unwrap();
+
+    // Allowlisting the configured bytecode lets the proof skip the configurables
+    registry
+        .methods()
+        .add_template(Bits256(*code_root), ContractType::Collection, vec![], Some(registry_offset(&bytecode, registry_id.into())))
+        .call()
+        .await
+        .unwrap();
+
+    DEPLOYMENTS.lock().unwrap().push((
+        id.clone().into(),
+        Deployment {
+            bytecode,
+            configurables: None,
+            salt: Bits256(DEPLOYMENT_SALT),
+            state_root: Bits256(*state_root),
+        },
+    ));
+
+    id
+}
+
+// Deploys a collection without a registry, whose constructor can never be called
+pub(crate) async fn deploy_without_registry(wallet: &WalletUnlocked) -> Props721Collection<WalletUnlocked> {
+    let id = Contract::load_from(NFT_CONTRACT_BINARY_PATH, LoadConfiguration::default().with_salt([1u8; 32]))
+        .unwrap()
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+    Props721Collection::new(id, wallet.clone())
+}
+
+async fn setup_with_configurables(configurables: Props721CollectionConfigurables) -> (
+    WalletUnlocked,
+    WalletUnlocked,
+    ContractId,
+    Props721Collection<WalletUnlocked>,
+    Props721Collection<WalletUnlocked>,
+    ContractId,
+    PropsFeeSplitter<WalletUnlocked>,
 ) {
     let number_of_coins = 1;
     let coin_amount = 100_000_000;
@@ -95,11 +257,7 @@ pub(crate) async fn setup() -> (
     let wallet1 = wallets.pop().unwrap();
     let wallet2 = wallets.pop().unwrap();
 
-    let id = Contract::load_from(NFT_CONTRACT_BINARY_PATH, LoadConfiguration::default())
-        .unwrap()
-        .deploy(&wallet1, TxPolicies::default())
-        .await
-        .unwrap();
+    let id = deploy_collection(&wallet1, configurables).await;
 
     let instance_1 = Props721Collection::new(id.clone(), wallet1.clone());
     let instance_2 = Props721Collection::new(id.clone(), wallet2.clone());
@@ -114,14 +272,6 @@ pub(crate) async fn setup() -> (
 
     println!("fee_id hash: {:?}", fee_id.hash());
 
-    let registry_id = Contract::load_from(REGISTRY_CONTRACT_BINARY_PATH, LoadConfiguration::default())
-        .unwrap()
-        .deploy(&wallet1, TxPolicies::default())
-        .await
-        .unwrap();
-
-    println!("registry_id hash: {:?}", registry_id.hash());
-
     (wallet1, wallet2, id.into(), instance_1, instance_2, fee_id.into(), fee_instance_1)
 }
 
@@ -174,13 +324,7 @@ pub(crate) async fn deploy_collection_with_builder_fee(mode: Option<u8>) -> (
 
     println!("configurables: {:?}", configurables);
 
-    let id = Contract::load_from(NFT_CONTRACT_BINARY_PATH, LoadConfiguration::default()
-        .with_configurables(configurables)
-    )
-        .unwrap()
-        .deploy(&wallet1, TxPolicies::default())
-        .await
-        .unwrap();
+    let id = deploy_collection(&wallet1, configurables).await;
 
     let instance_1 = Props721Collection::new(id.clone(), wallet1.clone());
     let instance_2 = Props721Collection::new(id.clone(), wallet2.clone());
@@ -193,14 +337,6 @@ pub(crate) async fn deploy_collection_with_builder_fee(mode: Option<u8>) -> (
 
     let fee_instance_1 = PropsFeeSplitter::new(fee_id.clone(), wallet1.clone());
 
-    let registry_id = Contract::load_from(REGISTRY_CONTRACT_BINARY_PATH, LoadConfiguration::default())
-        .unwrap()
-        .deploy(&wallet1, TxPolicies::default())
-        .await
-        .unwrap();
-
-    println!("registry_id hash: {:?}", registry_id.hash());
-
     (wallet1, wallet2, wallet3, id.into(), instance_1, instance_2, fee_id.into(), fee_instance_1)
 }
 
@@ -211,6 +347,26 @@ pub(crate) fn get_asset_id(sub_id: Bytes32, contract: ContractId) -> AssetId {
     AssetId::new(*Bytes32::from(<[u8; 32]>::from(hasher.finalize())))
 }
 
+fn role(name: &str) -> Bits256 {
+    Bits256(<[u8; 32]>::from(Sha256::digest(name.as_bytes())))
+}
+
+pub(crate) fn minter_role() -> Bits256 {
+    role("props.role.minter")
+}
+
+pub(crate) fn metadata_role() -> Bits256 {
+    role("props.role.metadata")
+}
+
+pub(crate) fn pauser_role() -> Bits256 {
+    role("props.role.pauser")
+}
+
+pub(crate) fn finance_role() -> Bits256 {
+    role("props.role.finance")
+}
+
 pub(crate) async fn get_wallet_balance(wallet: &WalletUnlocked, asset: &AssetId) -> u64 {
     wallet.get_asset_balance(asset).await.unwrap()
 }
@@ -11,8 +11,28 @@ pub enum MintError {
     OutsideMintingPeriod: String,
     InvalidProof: (),
     ExceededMaxMintLimit: (),
+    ExceededMaxPerTransaction: (),
+    MintCooldownActive: (),
+    ExceededMaxMintsPerBlock: (),
+    ProtocolPaused: (),
+    InvalidAmount: (),
+    SupplyLocked: (),
 }
 
 pub enum SetError {
     ValueAlreadySet: (),
+    MetadataFrozen: (),
+    InvalidDecimals: (),
+    InvalidMaxSupply: (),
+    InvalidMintWindow: (),
+    SupplyLocked: (),
+    OpenEdition: (),
+    MintWindowEnded: (),
+    InvalidFeePercentages: (),
+}
+
+pub enum AttributeError {
+    LengthMismatch: (),
+    InvalidAttributeType: (),
+    AssetNotMinted: (),
 }
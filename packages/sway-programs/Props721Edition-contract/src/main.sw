@@ -3,7 +3,7 @@ contract;
 mod errors;
 mod interface;
 
-use errors::{MintError, SetError};
+use errors::{AttributeError, MintError, SetError};
 use interface::{Props721Edition, SRC7MetadataExtension};
 use standards::{src20::SRC20, src3::SRC3, src5::{SRC5, State}, src7::{Metadata, SRC7},};
 use standards::src20::{SetNameEvent, SetSymbolEvent, SetDecimalsEvent, TotalSupplyEvent};
@@ -25,11 +25,6 @@ use sway_libs::{
             _mint,
         },
     },
-    ownership::{
-        _owner,
-        initialize_ownership,
-        only_owner,
-    },
     pausable::{
         _is_paused,
         _pause,
@@ -42,13 +37,17 @@ use sway_libs::{
 };
 use std::{hash::*, storage::storage_string::*, storage::storage_vec::*, string::String, bytes::Bytes, bytes_conversions::{b256::*, u16::*, u256::*, u32::*, u64::*,}, block::height};
 use std::logging::log;
-use std::context::msg_amount;
+use std::context::{msg_amount, this_balance};
 use std::auth::msg_sender;
 use std::call_frames::msg_asset_id;
 use std::asset::{transfer};
 use std::block::timestamp;
 
 use libraries::*;
+use libraries::roles::{_grant_role, _has_role, _revoke_role, only_role, FINANCE_ROLE, METADATA_ROLE, MINTER_ROLE, PAUSER_ROLE};
+use libraries::ownership::{_accept_ownership, _renounce_ownership, _transfer_ownership, only_registry};
+use sway_libs::ownership::{_owner, initialize_ownership, only_owner};
+use libraries::sweep::_sweep;
 
 // release
 const FEE_CONTRACT_ID = 0xe63564f83a2b82b97ea3f42d1680eeca825e3596b76da197ea4f6f6595810562;
@@ -97,6 +96,10 @@ storage {
 
     /// The start date for minting.
     ///
+    /// # Additional Information
+    ///
+    /// A value of `0` means minting has no start date.
+    ///
     /// # Type
     ///
     /// `u64`
@@ -104,6 +107,10 @@ storage {
 
     /// The end date for minting.
     ///
+    /// # Additional Information
+    ///
+    /// A value of `0` means minting has no end date.
+    ///
     /// # Type
     ///
     /// `u64`
@@ -123,12 +130,70 @@ storage {
     /// `StorageMap<Identity, u64>`
     minted_by_address: StorageMap<Identity, u64> = StorageMap {},
 
+    /// The time of the last mint of each minter, tracked when a mint cooldown is configured.
+    ///
+    /// # Type
+    ///
+    /// `StorageMap<Identity, u64>`
+    last_mint_time: StorageMap<Identity, u64> = StorageMap {},
+
+    /// The block height of the last mint of each minter, tracked when a block mint cooldown is configured.
+    ///
+    /// # Type
+    ///
+    /// `StorageMap<Identity, u32>`
+    last_mint_height: StorageMap<Identity, u32> = StorageMap {},
+
+    /// The block height of the last mint, tracked when a per-block mint limit is configured.
+    ///
+    /// # Type
+    ///
+    /// `u32`
+    last_mint_block: u32 = 0,
+
+    /// The number of NFTs minted in `last_mint_block`.
+    ///
+    /// # Type
+    ///
+    /// `u64`
+    mints_in_block: u64 = 0,
+
     /// The Merkle URI for the allowlist.
     ///
     /// # Type
     ///
     /// `StorageString`
     merkle_uri: StorageString = StorageString {},
+
+    /// The on-chain attributes associated with a particular asset.
+    ///
+    /// # Additional Information
+    ///
+    /// Attributes are exposed through SRC-7 under the `attr:<name>` key.
+    attributes: StorageMetadata = StorageMetadata {},
+
+    /// The attribute names that have been set for a particular asset.
+    attribute_keys: StorageMap<AssetId, StorageVec<StorageString>> = StorageMap {},
+
+    /// Whether the name, symbol and metadata of the collection have been frozen.
+    ///
+    /// # Additional Information
+    ///
+    /// Once frozen, metadata can no longer be changed by anyone, including the owner.
+    metadata_frozen: bool = false,
+
+    /// The maximum number of NFTs that may be minted, once it has been reduced by the owner.
+    ///
+    /// # Additional Information
+    ///
+    /// Falls back to the `MAX_SUPPLY` configurable until `reduce_max_supply` is called.
+    max_supply: Option<u64> = None,
+
+    /// Whether the maximum supply can no longer be changed.
+    supply_locked: bool = false,
+
+    /// The identity proposed as new owner, until it accepts.
+    pending_owner: Option<Identity> = None,
 }
 
 configurable {
@@ -184,6 +249,61 @@ configurable {
     ///
     /// `bool`
     DISABLE_AIRDROP: bool = false,
+
+    /// The maximum number of NFTs that may be minted in a single transaction.
+    ///
+    /// A value of `0` disables the limit.
+    ///
+    /// # Type
+    ///
+    /// `u64`
+    MAX_PER_TRANSACTION: u64 = 0,
+
+    /// The number of seconds a wallet has to wait between two mints.
+    ///
+    /// A value of `0` disables the cooldown.
+    ///
+    /// # Type
+    ///
+    /// `u64`
+    MINT_COOLDOWN: u64 = 0,
+
+    /// The number of blocks a wallet has to wait between two mints.
+    ///
+    /// A value of `0` disables the cooldown.
+    ///
+    /// # Type
+    ///
+    /// `u32`
+    MINT_COOLDOWN_BLOCKS: u32 = 0,
+
+    /// The maximum number of NFTs that may be minted in a single block across all wallets.
+    ///
+    /// A value of `0` disables the limit.
+    ///
+    /// # Type
+    ///
+    /// `u64`
+    MAX_MINTS_PER_BLOCK: u64 = 0,
+
+    /// A flag to turn the contract into an open edition.
+    ///
+    /// Open editions ignore `MAX_SUPPLY` and are only limited by the mint window.
+    /// The supply is locked once the end date has passed.
+    ///
+    /// # Type
+    ///
+    /// `bool`
+    OPEN_EDITION: bool = false,
+
+    /// The registry that is allowed to call the constructor.
+    ///
+    /// Must be set at deployment; with a zero id the constructor cannot be called at all.
+    ///
+    /// # Type
+    ///
+    /// `ContractId`
+    REGISTRY_CONTRACT_ID: ContractId = ContractId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
 }
 
 impl SRC20 for Contract {
@@ -338,6 +458,45 @@ impl SRC20 for Contract {
     }
 }
 
+/// Returns what minting `amount` NFTs at `price` charges, split exactly as `mint` pays it out.
+fn _quote_mint(price: u64, amount: u64, affiliate: Option<Identity>, protocol: ProtocolConfig) -> MintQuote {
+    require(amount > 0, MintError::InvalidAmount);
+
+    let subtotal = price.multiply(amount);
+
+    let builder_fee = if BUILDER_FEE_ADDRESS != Address::from(0x0000000000000000000000000000000000000000000000000000000000000000) {
+        BUILDER_FEE
+    } else {
+        0
+    };
+
+    let builder_revenue_share = if BUILDER_REVENUE_SHARE_ADDRESS != Address::from(0x0000000000000000000000000000000000000000000000000000000000000000) {
+        (subtotal * BUILDER_REVENUE_SHARE_PERCENTAGE) / 100
+    } else {
+        0
+    };
+
+    let affiliate_fee = match affiliate {
+        Some(Identity::Address(_)) => (subtotal * affiliate_fee_percentage(protocol, AFFILIATE_FEE_PERCENTAGE, BUILDER_REVENUE_SHARE_PERCENTAGE)) / 100,
+        _ => 0,
+    };
+
+    let fee_splitter = abi(PropsFeeSplitter, fee_contract_id(protocol, FEE_CONTRACT_ID));
+    let protocol_fee = fee_splitter.quote_fee_for(ContractId::this(), price, amount);
+
+    // The fixed builder fee is always charged and goes to the creator when no builder is set
+    let total = subtotal + protocol_fee + BUILDER_FEE;
+
+    MintQuote {
+        creator_price: total - protocol_fee - builder_fee - builder_revenue_share - affiliate_fee,
+        protocol_fee,
+        builder_fee,
+        builder_revenue_share,
+        affiliate_fee,
+        total,
+    }
+}
+
 #[storage(read, write), payable]
 fn _mint_core(
     recipient: Identity,
@@ -352,8 +511,13 @@ fn _mint_core(
     end_date: StorageKey<u64>,
     merkle_root: StorageKey<b256>,
     minted_by_address: StorageKey<StorageMap<Identity, u64>>,
+    last_mint_time: StorageKey<StorageMap<Identity, u64>>,
+    last_mint_height: StorageKey<StorageMap<Identity, u32>>,
+    last_mint_block: StorageKey<u32>,
+    mints_in_block: StorageKey<u64>,
     price: StorageKey<u64>,
     total_assets: StorageKey<u64>,
+    max_supply: StorageKey<Option<u64>>,
     last_minted_id: StorageKey<u64>,
     total_supply: StorageKey<StorageMap<AssetId, u64>>,
     name: StorageKey<StorageString>,
@@ -364,21 +528,27 @@ fn _mint_core(
     reentrancy_guard();
     require_not_paused();
 
+    let protocol = protocol_config(REGISTRY_CONTRACT_ID);
+    require(!protocol.paused, MintError::ProtocolPaused);
+
     // Checking mint dates
     let current_time = timestamp();
     let start_date = start_date.try_read().unwrap_or(0);
     let end_date = end_date.try_read().unwrap_or(0);
 
     require(
-        current_time >= start_date,
+        start_date == 0 || current_time >= start_date,
         MintError::OutsideMintingPeriod(String::from_ascii_str("Minting has not started yet."))
     );
 
     require(
-        current_time <= end_date,
+        end_date == 0 || current_time <= end_date,
         MintError::OutsideMintingPeriod(String::from_ascii_str("Minting has ended."))
     );
 
+    // Checking anti-bot limits
+    _enforce_mint_limits(amount, last_mint_time, last_mint_height, last_mint_block, mints_in_block);
+
     // Checking merkle proof
     let root = merkle_root.try_read().unwrap_or(b256::zero());
     if root != b256::zero() {
@@ -409,10 +579,6 @@ fn _mint_core(
         );
     }
 
-    let mut total_price: u64 = 0;
-    let mut total_fee: u64 = 0;
-    let mut affiliate_fee: u64 = 0;
-
     let price = price.try_read().unwrap_or(0);
     let _total_assets = total_assets.try_read().unwrap_or(0);
     let mut last_minted_id_value = last_minted_id.try_read().unwrap_or(0);
@@ -422,51 +588,42 @@ fn _mint_core(
     let asset_id = msg_asset_id();
 
     require(asset_id == AssetId::base(), MintError::InvalidAsset);
-    require(
-        _total_assets + amount <= MAX_SUPPLY,
-        MintError::MaxNFTsMinted,
-    );
+    if !OPEN_EDITION {
+        require(
+            _total_assets + amount <= _max_supply(max_supply),
+            MintError::MaxNFTsMinted,
+        );
+    }
 
-    // Check and transfer builder fee
-    if BUILDER_FEE_ADDRESS != Address::from(0x0000000000000000000000000000000000000000000000000000000000000000) {
-        if BUILDER_FEE > 0 {
-            // Fixed fee mode
-            total_fee += BUILDER_FEE;
-            transfer(Identity::Address(BUILDER_FEE_ADDRESS), AssetId::base(), BUILDER_FEE);
-        }
+    let quote = _quote_mint(price, amount, affiliate, protocol);
+    let total_price = quote.total;
+    let fee = quote.protocol_fee;
+    let affiliate_fee = quote.affiliate_fee;
+    let total_fee = fee + quote.builder_fee + quote.builder_revenue_share + affiliate_fee;
+
+    require(price_amount >= total_price, MintError::NotEnoughTokens(total_price));
+
+    // Transfer builder and affiliate fees
+    if quote.builder_fee > 0 {
+        transfer(Identity::Address(BUILDER_FEE_ADDRESS), AssetId::base(), quote.builder_fee);
     }
 
-    if BUILDER_REVENUE_SHARE_ADDRESS != Address::from(0x0000000000000000000000000000000000000000000000000000000000000000) {
-        if BUILDER_REVENUE_SHARE_PERCENTAGE > 0 {
-            // Calculate the builder revenue share fee
-            let builder_fee = (price * BUILDER_REVENUE_SHARE_PERCENTAGE) / 100;
-            total_fee += builder_fee;
-            transfer(Identity::Address(BUILDER_REVENUE_SHARE_ADDRESS), AssetId::base(), builder_fee);
-        }
+    if quote.builder_revenue_share > 0 {
+        transfer(Identity::Address(BUILDER_REVENUE_SHARE_ADDRESS), AssetId::base(), quote.builder_revenue_share);
     }
 
-    // Check and transfer affiliate fee
-    if let Some(Identity::Address(affiliate_address)) = affiliate {
-        if AFFILIATE_FEE_PERCENTAGE > 0 {
-            affiliate_fee = (price * AFFILIATE_FEE_PERCENTAGE) / 100;
-            total_fee += affiliate_fee;
+    if affiliate_fee > 0 {
+        if let Some(Identity::Address(affiliate_address)) = affiliate {
             transfer(Identity::Address(affiliate_address), AssetId::base(), affiliate_fee);
         }
     }
 
-    let fee_splitter = abi(PropsFeeSplitter, FEE_CONTRACT_ID);
-    let fee = fee_splitter.fee().unwrap_or(0);
-
-    total_price = price.multiply(amount) + fee + BUILDER_FEE;
-    total_fee += fee;
-
-    require(price_amount >= total_price, MintError::NotEnoughTokens(total_price));
+    let fee_splitter = abi(PropsFeeSplitter, fee_contract_id(protocol, FEE_CONTRACT_ID));
 
     if fee > 0 {
         fee_splitter.receive_funds {
             coins: fee,
             asset_id: AssetId::base().bits(),
-            gas: 1_000_000
         }();
     }
 
@@ -547,6 +704,8 @@ fn _airdrop(
     recipient: Identity,
     amount: u64,
     total_assets: StorageKey<u64>,
+    max_supply: StorageKey<Option<u64>>,
+    end_date: StorageKey<u64>,
     last_minted_id: StorageKey<u64>,
     total_supply: StorageKey<StorageMap<AssetId, u64>>,
     name: StorageKey<StorageString>,
@@ -555,16 +714,24 @@ fn _airdrop(
     metadata: StorageKey<StorageMetadata>
 ) {
     require(!DISABLE_AIRDROP, "Airdrop is disabled");
-    only_owner();
+    only_role(MINTER_ROLE);
     require_not_paused();
 
     let total_assets_value = total_assets.try_read().unwrap_or(0);
     let mut last_minted_id_value = last_minted_id.try_read().unwrap_or(0);
 
-    require(
-        total_assets_value + amount <= MAX_SUPPLY,
-        MintError::MaxNFTsMinted,
-    );
+    if OPEN_EDITION {
+        let end_date_value = end_date.try_read().unwrap_or(0);
+        require(
+            end_date_value == 0 || timestamp() <= end_date_value,
+            MintError::SupplyLocked,
+        );
+    } else {
+        require(
+            total_assets_value + amount <= _max_supply(max_supply),
+            MintError::MaxNFTsMinted,
+        );
+    }
 
     let mut minted_count = 0;
 
@@ -615,6 +782,79 @@ fn _airdrop(
     last_minted_id.write(last_minted_id_value);
 }
 
+#[storage(read, write)]
+fn _enforce_mint_limits(
+    amount: u64,
+    last_mint_time: StorageKey<StorageMap<Identity, u64>>,
+    last_mint_height: StorageKey<StorageMap<Identity, u32>>,
+    last_mint_block: StorageKey<u32>,
+    mints_in_block: StorageKey<u64>
+) {
+    if MAX_PER_TRANSACTION != 0 {
+        require(
+            amount <= MAX_PER_TRANSACTION,
+            MintError::ExceededMaxPerTransaction,
+        );
+    }
+
+    // Cooldowns apply to the minter, so minting to fresh recipients does not get around them
+    let minter = msg_sender().unwrap();
+
+    if MINT_COOLDOWN != 0 {
+        let current_time = timestamp();
+        if let Some(last_time) = last_mint_time.get(minter).try_read() {
+            require(
+                current_time >= last_time + MINT_COOLDOWN,
+                MintError::MintCooldownActive,
+            );
+        }
+        last_mint_time.insert(minter, current_time);
+    }
+
+    if MINT_COOLDOWN_BLOCKS != 0 {
+        let current_block = height();
+        if let Some(last_height) = last_mint_height.get(minter).try_read() {
+            require(
+                current_block >= last_height + MINT_COOLDOWN_BLOCKS,
+                MintError::MintCooldownActive,
+            );
+        }
+        last_mint_height.insert(minter, current_block);
+    }
+
+    if MAX_MINTS_PER_BLOCK != 0 {
+        let current_block = height();
+        let minted_in_block = if last_mint_block.try_read().unwrap_or(0) == current_block {
+            mints_in_block.try_read().unwrap_or(0)
+        } else {
+            0
+        };
+        require(
+            minted_in_block + amount <= MAX_MINTS_PER_BLOCK,
+            MintError::ExceededMaxMintsPerBlock,
+        );
+        last_mint_block.write(current_block);
+        mints_in_block.write(minted_in_block + amount);
+    }
+}
+
+fn _require_valid_mint_window(start: u64, end: u64) {
+    require(
+        start == 0 || end == 0 || start < end,
+        SetError::InvalidMintWindow,
+    );
+}
+
+#[storage(read)]
+fn _max_supply(max_supply: StorageKey<Option<u64>>) -> u64 {
+    max_supply.try_read().unwrap_or(None).unwrap_or(MAX_SUPPLY)
+}
+
+#[storage(read)]
+fn _require_metadata_not_frozen(metadata_frozen: StorageKey<bool>) {
+    require(!metadata_frozen.try_read().unwrap_or(false), SetError::MetadataFrozen);
+}
+
 impl SRC3PayableExtension for Contract {
     /// Mints new assets using the `sub_id` sub-identifier in a sequential manner.
     ///
@@ -633,9 +873,15 @@ impl SRC3PayableExtension for Contract {
     /// # Reverts
     ///
     /// * When the contract is paused.
+    /// * When `REGISTRY_CONTRACT_ID` is set and the registry has paused minting.
+    /// * When `amount` is zero.
     /// * When amount is greater than one.
     /// * When the asset has already been minted.
-    /// * When more than the MAX_SUPPLY NFTs have been minted.
+    /// * When more than the maximum supply of NFTs have been minted and the contract is not an open edition.
+    /// * When `amount` is greater than `MAX_PER_TRANSACTION`.
+    /// * When the caller minted less than `MINT_COOLDOWN` seconds ago.
+    /// * When the caller minted less than `MINT_COOLDOWN_BLOCKS` blocks ago.
+    /// * When more than `MAX_MINTS_PER_BLOCK` NFTs would be minted in the current block.
     ///
     /// # Number of Storage Accesses
     ///
@@ -667,8 +913,13 @@ impl SRC3PayableExtension for Contract {
             storage.end_date,
             storage.merkle_root,
             storage.minted_by_address,
+            storage.last_mint_time,
+            storage.last_mint_height,
+            storage.last_mint_block,
+            storage.mints_in_block,
             storage.price,
             storage.total_assets,
+            storage.max_supply,
             storage.last_minted_id,
             storage.total_supply,
             storage.name,
@@ -678,7 +929,7 @@ impl SRC3PayableExtension for Contract {
         );
     }
 
-    /// Mints new assets to a recipient in a sequential manner. Only callable by the owner.
+    /// Mints new assets to a recipient in a sequential manner. Only callable by the owner or a minter.
     ///
     /// # Arguments
     ///
@@ -687,8 +938,10 @@ impl SRC3PayableExtension for Contract {
     ///
     /// # Reverts
     ///
+    /// * When the caller is not the contract owner and does not have the minter role.
     /// * When the contract is paused.
-    /// * When more than the MAX_SUPPLY NFTs have been minted.
+    /// * When more than the maximum supply of NFTs have been minted.
+    /// * When the contract is an open edition and its end date has passed.
     ///
     /// # Number of Storage Accesses
     ///
@@ -707,7 +960,7 @@ impl SRC3PayableExtension for Contract {
     /// ```
     #[storage(read, write)]
     fn airdrop(recipient: Identity, amount: u64) {
-        _airdrop(recipient, amount, storage.total_assets, storage.last_minted_id, storage.total_supply, storage.name, storage.symbol, storage.metadata_keys, storage.metadata)
+        _airdrop(recipient, amount, storage.total_assets, storage.max_supply, storage.end_date, storage.last_minted_id, storage.total_supply, storage.name, storage.symbol, storage.metadata_keys, storage.metadata)
     }
 
     /// Burns assets sent with the given `sub_id`.
@@ -788,6 +1041,10 @@ impl SRC7 for Contract {
     /// ```
     #[storage(read)]
     fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+        if let Some(name) = attribute_name(key) {
+            return storage.attributes.get(asset, name);
+        }
+
         // Return the same metadata for all assets
         storage.metadata.get(AssetId::from(SubId::zero()), key)
     }
@@ -881,145 +1138,163 @@ impl SRC7MetadataExtension for Contract {
     }
 }
 
-impl SRC5 for Contract {
-    /// Returns the owner.
+impl SetAssetAttributes for Contract {
+    /// Sets the name of the collection.
     ///
-    /// # Return Values
+    /// # Additional Information
     ///
-    /// * [State] - Represents the state of ownership for this contract.
+    /// The name is shared by every asset of this contract, `asset` is only used for the emitted event.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset for which the name change is reported.
+    /// * `name`: [String] - The new name.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the metadata role.
+    /// * When the metadata has been frozen.
     ///
     /// # Number of Storage Accesses
     ///
-    /// * Reads: `1`
+    /// * Reads: `2`
+    /// * Writes: `1`
     ///
     /// # Examples
     ///
     /// ```sway
-    /// use standards::src5::SRC5;
-    ///
-    /// fn foo(contract_id: ContractId) {
-    ///     let ownership_abi = abi(contract_id, SRC_5);
+    /// use sway_libs::asset::base::SetAssetAttributes;
+    /// use std::string::String;
     ///
-    ///     match ownership_abi.owner() {
-    ///         State::Uninitalized => log("The ownership is uninitalized"),
-    ///         State::Initialized(owner) => log("The ownership is initalized"),
-    ///         State::Revoked => log("The ownership is revoked"),
-    ///     }
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let set_abi = abi(SetAssetAttributes, contract_id);
+    ///     set_abi.set_name(asset, String::from_ascii_str("My NFT"));
     /// }
     /// ```
-    #[storage(read)]
-    fn owner() -> State {
-        _owner()
+    #[storage(write)]
+    fn set_name(asset: AssetId, name: String) {
+        only_role(METADATA_ROLE);
+        _require_metadata_not_frozen(storage.metadata_frozen);
+        storage.name.write_slice(name);
+        SetNameEvent::new(asset, Some(name), msg_sender().unwrap()).log();
     }
-}
 
-impl SetAssetMetadata for Contract {
-    /// Stores metadata for a specific asset and key pair.
+    /// Sets the symbol of the collection.
+    ///
+    /// # Additional Information
+    ///
+    /// The symbol is shared by every asset of this contract, `asset` is only used for the emitted event.
     ///
     /// # Arguments
     ///
-    /// * `asset`: [AssetId] - The asset for the metadata to be stored.
-    /// * `key`: [String] - The key for the metadata to be stored.
-    /// * `metadata`: [Metadata] - The metadata to be stored.
+    /// * `asset`: [AssetId] - The asset for which the symbol change is reported.
+    /// * `symbol`: [String] - The new symbol.
     ///
     /// # Reverts
     ///
-    /// * When the metadata has already been set for an asset.
+    /// * When the caller is not the contract owner and does not have the metadata role.
+    /// * When the metadata has been frozen.
     ///
     /// # Number of Storage Accesses
     ///
-    /// * Reads: `1`
-    /// * Writes: `2`
+    /// * Reads: `2`
+    /// * Writes: `1`
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```sway
-    /// use asset::metdata::SetAssetMetadata;
-    /// use src_7::{SRC7, Metadata};
+    /// use sway_libs::asset::base::SetAssetAttributes;
+    /// use std::string::String;
     ///
-    /// fn foo(asset: AssetId, key: String, contract_id: ContractId, metadata: Metadata) {
-    ///     let set_abi = abi(SetAssetMetadata, contract_id);
-    ///     let src_7_abi = abi(SRC7, contract);
-    ///     set_abi.set_metadata(storage.metadata, asset, key, metadata);
-    ///     assert(src_7_abi.metadata(asset, key) == metadata);
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let set_abi = abi(SetAssetAttributes, contract_id);
+    ///     set_abi.set_symbol(asset, String::from_ascii_str("NFT"));
     /// }
     /// ```
-    #[storage(read, write)]
-    fn set_metadata(asset: AssetId, key: String, metadata: Metadata) {
-        only_owner();
-        require(storage.metadata.get(AssetId::from(SubId::zero()), key).is_none(), SetError::ValueAlreadySet);
-        _set_metadata(storage.metadata, AssetId::from(SubId::zero()), key, metadata);
-        let sender = msg_sender().unwrap();
-        SetMetadataEvent::new(asset, Some(metadata), key, sender).log();
+    #[storage(write)]
+    fn set_symbol(asset: AssetId, symbol: String) {
+        only_role(METADATA_ROLE);
+        _require_metadata_not_frozen(storage.metadata_frozen);
+        storage.symbol.write_slice(symbol);
+        SetSymbolEvent::new(asset, Some(symbol), msg_sender().unwrap()).log();
     }
-}
 
-impl SetMintMetadata for Contract {
-    /// Sets the price for minting an NFT.
+    /// Sets the decimals of an asset.
+    ///
+    /// # Additional Information
+    ///
+    /// NFTs always use `0` decimals, so this only accepts `0` and leaves storage untouched.
     ///
     /// # Arguments
     ///
-    /// * `price`: [u128] - The price to mint an NFT.
+    /// * `asset`: [AssetId] - The asset for which the decimals are set.
+    /// * `decimals`: [u8] - The decimals, which must be `0`.
     ///
     /// # Reverts
     ///
     /// * When the caller is not the contract owner.
+    /// * When `decimals` is not `0`.
     ///
     /// # Number of Storage Accesses
     ///
-    /// * Writes: `1`
+    /// * Reads: `1`
     ///
     /// # Examples
     ///
     /// ```sway
-    /// use sway_libs::mint::SetMintMetadata;
+    /// use sway_libs::asset::base::SetAssetAttributes;
     ///
-    /// fn foo(contract_id: ContractId, price: u128) {
-    ///     let mint_abi = abi(SetMintMetadata, contract_id);
-    ///     mint_abi.set_price(price);
-    ///     assert(mint_abi.price() == price);
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let set_abi = abi(SetAssetAttributes, contract_id);
+    ///     set_abi.set_decimals(asset, 0u8);
     /// }
     /// ```
     #[storage(write)]
-    fn set_price(price: u64) {
+    fn set_decimals(_asset: AssetId, decimals: u8) {
         only_owner();
-        storage.price.write(price);
-        log(SetMintPriceEvent{
-            price
-        });
+        require(decimals == 0u8, SetError::InvalidDecimals);
     }
+}
 
-    /// Returns the price for minting an NFT.
+impl FreezeMetadata for Contract {
+    /// Permanently freezes the name, symbol and metadata of the collection.
     ///
-    /// # Returns
+    /// # Reverts
     ///
-    /// * [u128] - The price to mint an NFT.
+    /// * When the caller is not the contract owner.
+    /// * When the metadata has already been frozen.
     ///
     /// # Number of Storage Accesses
     ///
-    /// * Reads: `1`
+    /// * Reads: `2`
+    /// * Writes: `1`
     ///
     /// # Examples
     ///
     /// ```sway
-    /// use sway_libs::mint::SetMintMetadata;
+    /// use libraries::FreezeMetadata;
     ///
     /// fn foo(contract_id: ContractId) {
-    ///     let mint_abi = abi(SetMintMetadata, contract_id);
-    ///     let price = mint_abi.price();
-    ///     assert(price != 0);
+    ///     let freeze_abi = abi(FreezeMetadata, contract_id);
+    ///     freeze_abi.freeze_metadata();
+    ///     assert(freeze_abi.metadata_frozen());
     /// }
     /// ```
-    #[storage(read)]
-    fn price() -> Option<u64> {
-        Some(storage.price.try_read().unwrap_or(0))
+    #[storage(read, write)]
+    fn freeze_metadata() {
+        only_owner();
+        _require_metadata_not_frozen(storage.metadata_frozen);
+        storage.metadata_frozen.write(true);
+        log(MetadataFrozenEvent {
+            sender: msg_sender().unwrap()
+        });
     }
 
-    /// Returns the total price for minting an NFT, including any applicable fees.
+    /// Returns whether the metadata of the collection has been frozen.
     ///
     /// # Returns
     ///
-    /// * [Option<u64>] - The total price to mint an NFT.
+    /// * [bool] - `true` if the metadata can no longer be changed.
     ///
     /// # Number of Storage Accesses
     ///
@@ -1028,49 +1303,943 @@ impl SetMintMetadata for Contract {
     /// # Examples
     ///
     /// ```sway
-    /// use sway_libs::mint::SetMintMetadata;
+    /// use libraries::FreezeMetadata;
     ///
     /// fn foo(contract_id: ContractId) {
-    ///     let mint_abi = abi(SetMintMetadata, contract_id);
-    ///     let total_price = mint_abi.total_price();
-    ///     assert(total_price != 0);
+    ///     let freeze_abi = abi(FreezeMetadata, contract_id);
+    ///     assert(!freeze_abi.metadata_frozen());
     /// }
     /// ```
     #[storage(read)]
-    fn total_price() -> Option<u64> {
-        let base_price = storage.price.try_read().unwrap_or(0);
-        let fee_splitter = abi(PropsFeeSplitter, FEE_CONTRACT_ID);
-        let fee = fee_splitter.fee().unwrap_or(0);
-        Some(base_price + fee + BUILDER_FEE)
+    fn metadata_frozen() -> bool {
+        storage.metadata_frozen.try_read().unwrap_or(false)
     }
+}
 
-    /// Returns the breakdown of fees for minting an NFT.
+impl MintLimits for Contract {
+    /// Returns the maximum number of NFTs that may be minted in a single transaction.
     ///
     /// # Returns
     ///
-    /// * [Option<(u64, u64, u64)>] - A tuple containing the builder fee, and base fee.
-    ///
-    /// # Number of Storage Accesses
-    ///
-    /// * Reads: `1`
+    /// * [Option<u64>] - The limit, or `None` if there is no limit.
     ///
     /// # Examples
     ///
     /// ```sway
-    /// use sway_libs::mint::SetMintMetadata;
+    /// use libraries::MintLimits;
     ///
     /// fn foo(contract_id: ContractId) {
-    ///     let mint_abi = abi(SetMintMetadata, contract_id);
-    ///     let (builder_fee, base_fee) = mint_abi.fees().unwrap();
+    ///     let limits_abi = abi(MintLimits, contract_id);
+    ///     let limit = limits_abi.max_per_transaction();
+    ///     assert(limit.is_none());
     /// }
     /// ```
-    fn fees() -> Option<(u64, u64)> {
-        let fee_splitter = abi(PropsFeeSplitter, FEE_CONTRACT_ID);
-        let fee:u64 = fee_splitter.fee().unwrap_or(0);
-        Some((fee, BUILDER_FEE))
+    fn max_per_transaction() -> Option<u64> {
+        match MAX_PER_TRANSACTION {
+            0 => None,
+            limit => Some(limit),
+        }
     }
 
-    /// Returns the start date of the contract.
+    /// Returns the number of seconds a wallet has to wait between two mints.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The cooldown in seconds, or `None` if there is no cooldown.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::MintLimits;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let limits_abi = abi(MintLimits, contract_id);
+    ///     let cooldown = limits_abi.mint_cooldown();
+    ///     assert(cooldown.is_none());
+    /// }
+    /// ```
+    fn mint_cooldown() -> Option<u64> {
+        match MINT_COOLDOWN {
+            0 => None,
+            cooldown => Some(cooldown),
+        }
+    }
+
+    /// Returns the number of blocks a wallet has to wait between two mints.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u32>] - The cooldown in blocks, or `None` if there is no block cooldown.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::MintLimits;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let limits_abi = abi(MintLimits, contract_id);
+    ///     let cooldown = limits_abi.mint_cooldown_blocks();
+    ///     assert(cooldown.is_none());
+    /// }
+    /// ```
+    fn mint_cooldown_blocks() -> Option<u32> {
+        match MINT_COOLDOWN_BLOCKS {
+            0 => None,
+            cooldown => Some(cooldown),
+        }
+    }
+
+    /// Returns the maximum number of NFTs that may be minted in a single block.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The limit, or `None` if there is no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::MintLimits;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let limits_abi = abi(MintLimits, contract_id);
+    ///     let limit = limits_abi.max_mints_per_block();
+    ///     assert(limit.is_none());
+    /// }
+    /// ```
+    fn max_mints_per_block() -> Option<u64> {
+        match MAX_MINTS_PER_BLOCK {
+            0 => None,
+            limit => Some(limit),
+        }
+    }
+
+    /// Returns the time at which a wallet last minted.
+    ///
+    /// # Additional Information
+    ///
+    /// Mint times are only tracked while a mint cooldown is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet`: [Identity] - The wallet to look up.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The time of the last mint, or `None` if no mint has been tracked.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::MintLimits;
+    ///
+    /// fn foo(contract_id: ContractId, wallet: Identity) {
+    ///     let limits_abi = abi(MintLimits, contract_id);
+    ///     let last_time = limits_abi.last_mint_time(wallet);
+    ///     assert(last_time.is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn last_mint_time(wallet: Identity) -> Option<u64> {
+        storage.last_mint_time.get(wallet).try_read()
+    }
+
+    /// Returns the block height at which a wallet last minted.
+    ///
+    /// # Additional Information
+    ///
+    /// Mint heights are only tracked while a block mint cooldown is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet`: [Identity] - The wallet to look up.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u32>] - The height of the last mint, or `None` if no mint has been tracked.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::MintLimits;
+    ///
+    /// fn foo(contract_id: ContractId, wallet: Identity) {
+    ///     let limits_abi = abi(MintLimits, contract_id);
+    ///     let last_height = limits_abi.last_mint_height(wallet);
+    ///     assert(last_height.is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn last_mint_height(wallet: Identity) -> Option<u32> {
+        storage.last_mint_height.get(wallet).try_read()
+    }
+}
+
+impl SupplyControl for Contract {
+    /// Lowers the maximum number of NFTs that may be minted.
+    ///
+    /// # Additional Information
+    ///
+    /// The maximum supply can only ever decrease, which allows an undersold drop to be closed out early.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_max_supply`: [u64] - The new maximum supply.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When the contract is an open edition.
+    /// * When the supply has been locked.
+    /// * When `new_max_supply` is less than the number of minted NFTs.
+    /// * When `new_max_supply` is not less than the current maximum supply.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `4`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::SupplyControl;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let supply_abi = abi(SupplyControl, contract_id);
+    ///     supply_abi.reduce_max_supply(2);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn reduce_max_supply(new_max_supply: u64) {
+        only_owner();
+        require(!OPEN_EDITION, SetError::OpenEdition);
+        require(!storage.supply_locked.try_read().unwrap_or(false), SetError::SupplyLocked);
+
+        let previous_max_supply = _max_supply(storage.max_supply);
+        require(
+            new_max_supply >= storage.total_assets.try_read().unwrap_or(0) && new_max_supply < previous_max_supply,
+            SetError::InvalidMaxSupply,
+        );
+
+        storage.max_supply.write(Some(new_max_supply));
+
+        log(MaxSupplyChangedEvent {
+            previous_max_supply,
+            new_max_supply,
+            sender: msg_sender().unwrap()
+        });
+    }
+
+    /// Permanently prevents the maximum supply from being changed.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When the contract is an open edition.
+    /// * When the supply has already been locked.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `3`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::SupplyControl;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let supply_abi = abi(SupplyControl, contract_id);
+    ///     supply_abi.lock_supply();
+    ///     assert(supply_abi.supply_locked());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn lock_supply() {
+        only_owner();
+        require(!OPEN_EDITION, SetError::OpenEdition);
+        require(!storage.supply_locked.try_read().unwrap_or(false), SetError::SupplyLocked);
+
+        storage.supply_locked.write(true);
+
+        log(SupplyLockedEvent {
+            max_supply: _max_supply(storage.max_supply),
+            sender: msg_sender().unwrap()
+        });
+    }
+
+    /// Returns whether the maximum supply has been locked.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the maximum supply can no longer be changed.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::SupplyControl;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let supply_abi = abi(SupplyControl, contract_id);
+    ///     assert(!supply_abi.supply_locked());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn supply_locked() -> bool {
+        storage.supply_locked.try_read().unwrap_or(false)
+    }
+}
+
+impl SetTokenAttributes for Contract {
+    /// Sets a batch of attributes for a specific asset.
+    ///
+    /// # Additional Information
+    ///
+    /// Existing attributes with the same name are overwritten. Every attribute is readable
+    /// through SRC-7 `metadata(asset, "attr:<name>")`.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset for which the attributes are set.
+    /// * `keys`: [Vec<String>] - The names of the attributes.
+    /// * `values`: [Vec<Metadata>] - The values of the attributes, either `Metadata::String` or `Metadata::Int`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the metadata role.
+    /// * When the length of `keys` and `values` do not match.
+    /// * When a value is not a string or an integer.
+    /// * When the asset has not been minted.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2 + 2N` where `N` is the number of attributes
+    /// * Writes: `N` to `3N` where `N` is the number of attributes
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::SetTokenAttributes;
+    /// use std::string::String;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let attributes_abi = abi(SetTokenAttributes, contract_id);
+    ///     attributes_abi.set_attributes(asset, vec![String::from_ascii_str("level")], vec![Metadata::Int(5)]);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_attributes(asset: AssetId, keys: Vec<String>, values: Vec<Metadata>) {
+        only_role(METADATA_ROLE);
+        require(keys.len() == values.len(), AttributeError::LengthMismatch);
+        require(storage.total_supply.get(asset).try_read().is_some(), AttributeError::AssetNotMinted);
+
+        let sender = msg_sender().unwrap();
+        let mut i = 0;
+        while i < keys.len() {
+            let key = keys.get(i).unwrap();
+            let value = values.get(i).unwrap();
+            let is_typed = match value {
+                Metadata::String(_) => true,
+                Metadata::Int(_) => true,
+                _ => false,
+            };
+            require(is_typed, AttributeError::InvalidAttributeType);
+
+            if storage.attributes.get(asset, key).is_none() {
+                let asset_keys = storage.attribute_keys.get(asset);
+                asset_keys.push(StorageString {});
+                asset_keys.get(asset_keys.len() - 1).unwrap().write_slice(key);
+            }
+
+            _set_metadata(storage.attributes, asset, key, value);
+            SetMetadataEvent::new(asset, Some(value), attribute_key(key), sender).log();
+            i += 1;
+        }
+    }
+
+    /// Returns a page of the attributes set for a specific asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset of which to query the attributes.
+    /// * `offset`: [u64] - The index of the first attribute to return.
+    /// * `limit`: [u64] - The maximum number of attributes to return.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<(String, Metadata)>] - The attribute name and value pairs in the requested page.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1 + 2N` where `N` is the number of attributes returned
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::SetTokenAttributes;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let attributes_abi = abi(SetTokenAttributes, contract_id);
+    ///     let attributes = attributes_abi.attributes(asset, 0, 10);
+    ///     assert(attributes.len() <= 10);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn attributes(asset: AssetId, offset: u64, limit: u64) -> Vec<(String, Metadata)> {
+        let asset_keys = storage.attribute_keys.get(asset);
+        let mut result = Vec::new();
+
+        let mut i = offset;
+        while i < asset_keys.len() && result.len() < limit {
+            let key = asset_keys.get(i).unwrap().read_slice().unwrap();
+            if let Some(value) = storage.attributes.get(asset, key) {
+                result.push((key, value));
+            }
+            i += 1;
+        }
+
+        result
+    }
+}
+
+impl Sweep for Contract {
+    /// Transfers assets held by the contract to a recipient.
+    ///
+    /// # Additional Information
+    ///
+    /// Mint proceeds and fees are forwarded during `mint`, so the contract holds no funds owed to
+    /// anyone. This recovers assets sent by mistake, NFTs returned to the contract and the proceeds
+    /// kept for an owner that is a `ContractId`.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to transfer.
+    /// * `amount`: [u64] - The amount of `asset` to transfer.
+    /// * `recipient`: [Identity] - The identity receiving the assets.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When `amount` is zero.
+    /// * When `amount` is greater than the balance of `asset`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Sweep;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId, recipient: Identity) {
+    ///     let sweep_abi = abi(Sweep, contract_id);
+    ///     sweep_abi.sweep(asset, sweep_abi.sweepable(asset), recipient);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn sweep(asset: AssetId, amount: u64, recipient: Identity) {
+        _sweep(asset, amount, recipient, this_balance(asset));
+    }
+
+    /// Returns how much of an asset the owner can sweep.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to check.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The balance of `asset` held by the contract.
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Sweep;
+    ///
+    /// fn foo(contract_id: ContractId, asset: AssetId) {
+    ///     let sweep_abi = abi(Sweep, contract_id);
+    ///     assert(sweep_abi.sweepable(asset) == 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn sweepable(asset: AssetId) -> u64 {
+        this_balance(asset)
+    }
+}
+
+impl SRC5 for Contract {
+    /// Returns the owner.
+    ///
+    /// # Return Values
+    ///
+    /// * [State] - Represents the state of ownership for this contract.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use standards::src5::SRC5;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownership_abi = abi(contract_id, SRC_5);
+    ///
+    ///     match ownership_abi.owner() {
+    ///         State::Uninitalized => log("The ownership is uninitalized"),
+    ///         State::Initialized(owner) => log("The ownership is initalized"),
+    ///         State::Revoked => log("The ownership is revoked"),
+    ///     }
+    /// }
+    /// ```
+    #[storage(read)]
+    fn owner() -> State {
+        _owner()
+    }
+}
+
+impl Ownable for Contract {
+    /// Proposes a new owner for the contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Ownership only changes once `new_owner` calls `accept_ownership`. Proposing another
+    /// identity replaces the pending proposal.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner`: [Identity] - The identity proposed as new owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    ///
+    /// fn foo(contract_id: ContractId, new_owner: Identity) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.transfer_ownership(new_owner);
+    ///     assert(ownable_abi.pending_owner() == Some(new_owner));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        _transfer_ownership(new_owner, storage.pending_owner);
+    }
+
+    /// Accepts a pending ownership transfer, making the caller the owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the pending owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    /// use standards::src5::{SRC5, State};
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.accept_ownership();
+    ///     assert(abi(SRC5, contract_id).owner() == State::Initialized(msg_sender().unwrap()));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn accept_ownership() {
+        _accept_ownership(storage.pending_owner);
+    }
+
+    /// Permanently renounces ownership of the contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Any pending ownership transfer is cancelled. Owner-only functions can no longer be called afterwards.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    /// use standards::src5::{SRC5, State};
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.renounce_ownership();
+    ///     assert(abi(SRC5, contract_id).owner() == State::Revoked);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn renounce_ownership() {
+        _renounce_ownership(storage.pending_owner);
+    }
+
+    /// Returns the identity that has been proposed as new owner.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Identity>] - The pending owner, or `None` if no transfer is pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     assert(ownable_abi.pending_owner().is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity> {
+        storage.pending_owner.read()
+    }
+}
+
+impl AccessControl for Contract {
+    /// Grants a role to an account.
+    ///
+    /// # Additional Information
+    ///
+    /// The owner implicitly holds every role. Available roles are `MINTER_ROLE` (airdrop and allowlist),
+    /// `METADATA_ROLE` (metadata), `PAUSER_ROLE` (pause and unpause) and `FINANCE_ROLE` (price).
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to grant.
+    /// * `account`: [Identity] - The account that receives the role.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::{AccessControl, roles::MINTER_ROLE};
+    ///
+    /// fn foo(contract_id: ContractId, bot: Identity) {
+    ///     let access_abi = abi(AccessControl, contract_id);
+    ///     access_abi.grant_role(MINTER_ROLE, bot);
+    ///     assert(access_abi.has_role(MINTER_ROLE, bot));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity) {
+        _grant_role(role, account);
+    }
+
+    /// Revokes a role from an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to revoke.
+    /// * `account`: [Identity] - The account that loses the role.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::{AccessControl, roles::MINTER_ROLE};
+    ///
+    /// fn foo(contract_id: ContractId, bot: Identity) {
+    ///     let access_abi = abi(AccessControl, contract_id);
+    ///     access_abi.revoke_role(MINTER_ROLE, bot);
+    ///     assert(!access_abi.has_role(MINTER_ROLE, bot));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn revoke_role(role: b256, account: Identity) {
+        _revoke_role(role, account);
+    }
+
+    /// Returns whether an account has been granted a role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role`: [b256] - The role to check.
+    /// * `account`: [Identity] - The account to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if `account` has been granted `role`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::{AccessControl, roles::PAUSER_ROLE};
+    ///
+    /// fn foo(contract_id: ContractId, account: Identity) {
+    ///     let access_abi = abi(AccessControl, contract_id);
+    ///     assert(!access_abi.has_role(PAUSER_ROLE, account));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn has_role(role: b256, account: Identity) -> bool {
+        _has_role(role, account)
+    }
+}
+
+impl SetAssetMetadata for Contract {
+    /// Stores metadata for a specific asset and key pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset for the metadata to be stored.
+    /// * `key`: [String] - The key for the metadata to be stored.
+    /// * `metadata`: [Metadata] - The metadata to be stored.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the metadata role.
+    /// * When the metadata has already been set for an asset.
+    /// * When the metadata has been frozen.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `2`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use asset::metdata::SetAssetMetadata;
+    /// use src_7::{SRC7, Metadata};
+    ///
+    /// fn foo(asset: AssetId, key: String, contract_id: ContractId, metadata: Metadata) {
+    ///     let set_abi = abi(SetAssetMetadata, contract_id);
+    ///     let src_7_abi = abi(SRC7, contract);
+    ///     set_abi.set_metadata(storage.metadata, asset, key, metadata);
+    ///     assert(src_7_abi.metadata(asset, key) == metadata);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_metadata(asset: AssetId, key: String, metadata: Metadata) {
+        only_role(METADATA_ROLE);
+        _require_metadata_not_frozen(storage.metadata_frozen);
+        require(storage.metadata.get(AssetId::from(SubId::zero()), key).is_none(), SetError::ValueAlreadySet);
+        _set_metadata(storage.metadata, AssetId::from(SubId::zero()), key, metadata);
+        let sender = msg_sender().unwrap();
+        SetMetadataEvent::new(asset, Some(metadata), key, sender).log();
+    }
+}
+
+impl SetMintMetadata for Contract {
+    /// Sets the price for minting an NFT.
+    ///
+    /// # Arguments
+    ///
+    /// * `price`: [u128] - The price to mint an NFT.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the finance role.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use sway_libs::mint::SetMintMetadata;
+    ///
+    /// fn foo(contract_id: ContractId, price: u128) {
+    ///     let mint_abi = abi(SetMintMetadata, contract_id);
+    ///     mint_abi.set_price(price);
+    ///     assert(mint_abi.price() == price);
+    /// }
+    /// ```
+    #[storage(write)]
+    fn set_price(price: u64) {
+        only_role(FINANCE_ROLE);
+        storage.price.write(price);
+        log(SetMintPriceEvent{
+            price
+        });
+    }
+
+    /// Returns the price for minting an NFT.
+    ///
+    /// # Returns
+    ///
+    /// * [u128] - The price to mint an NFT.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use sway_libs::mint::SetMintMetadata;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let mint_abi = abi(SetMintMetadata, contract_id);
+    ///     let price = mint_abi.price();
+    ///     assert(price != 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn price() -> Option<u64> {
+        Some(storage.price.try_read().unwrap_or(0))
+    }
+
+    /// Returns the total price for minting an NFT, including any applicable fees.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The total price to mint an NFT.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use sway_libs::mint::SetMintMetadata;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let mint_abi = abi(SetMintMetadata, contract_id);
+    ///     let total_price = mint_abi.total_price();
+    ///     assert(total_price != 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn total_price() -> Option<u64> {
+        let base_price = storage.price.try_read().unwrap_or(0);
+        let fee_splitter = abi(PropsFeeSplitter, fee_contract_id(protocol_config(REGISTRY_CONTRACT_ID), FEE_CONTRACT_ID));
+        let fee = fee_splitter.quote_fee_for(ContractId::this(), base_price, 1);
+        Some(base_price + fee + BUILDER_FEE)
+    }
+
+    /// Returns the breakdown of fees for minting an NFT.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<(u64, u64, u64)>] - A tuple containing the builder fee, and base fee.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use sway_libs::mint::SetMintMetadata;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let mint_abi = abi(SetMintMetadata, contract_id);
+    ///     let (builder_fee, base_fee) = mint_abi.fees().unwrap();
+    /// }
+    /// ```
+    #[storage(read)]
+    fn fees() -> Option<(u64, u64)> {
+        let base_price = storage.price.try_read().unwrap_or(0);
+        let fee_splitter = abi(PropsFeeSplitter, fee_contract_id(protocol_config(REGISTRY_CONTRACT_ID), FEE_CONTRACT_ID));
+        let fee:u64 = fee_splitter.quote_fee_for(ContractId::this(), base_price, 1);
+        Some((fee, BUILDER_FEE))
+    }
+
+    /// Returns exactly what minting NFTs charges and how the payment is split.
+    ///
+    /// # Additional Information
+    ///
+    /// `mint` uses the same calculation, so forwarding `total` always covers the mint. Fees that are
+    /// a percentage of the price apply to the price of all NFTs minted, as in `mint`.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient`: [Identity] - The identity that would receive the NFTs. The price does not currently depend on it.
+    /// * `amount`: [u64] - The number of NFTs to mint.
+    /// * `affiliate`: [Option<Identity>] - The affiliate that would receive a share of the price.
+    ///
+    /// # Returns
+    ///
+    /// * [MintQuote] - The creator price, each fee and the total to forward.
+    ///
+    /// # Reverts
+    ///
+    /// * When `amount` is zero.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::SetMintMetadata;
+    ///
+    /// fn foo(contract_id: ContractId, recipient: Identity) {
+    ///     let mint_abi = abi(SetMintMetadata, contract_id);
+    ///     let quote = mint_abi.quote_mint(recipient, 2, None);
+    ///     assert(quote.total >= quote.creator_price);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn quote_mint(recipient: Identity, amount: u64, affiliate: Option<Identity>) -> MintQuote {
+        let price = storage.price.try_read().unwrap_or(0);
+        _quote_mint(price, amount, affiliate, protocol_config(REGISTRY_CONTRACT_ID))
+    }
+
+    /// Returns the start date of the contract.
     ///
     /// # Returns
     ///
@@ -1093,7 +2262,10 @@ impl SetMintMetadata for Contract {
     /// ```
     #[storage(read)]
     fn start_date() -> Option<u64> {
-        Some(storage.start_date.try_read().unwrap_or(0))
+        match storage.start_date.try_read().unwrap_or(0) {
+            0 => None,
+            date => Some(date),
+        }
     }
 
     /// Returns the end date of the contract.
@@ -1119,18 +2291,33 @@ impl SetMintMetadata for Contract {
     /// ```
     #[storage(read)]
     fn end_date() -> Option<u64> {
-        Some(storage.end_date.try_read().unwrap_or(0))
+        match storage.end_date.try_read().unwrap_or(0) {
+            0 => None,
+            date => Some(date),
+        }
     }
 
     /// Sets the start and end dates for the contract.
     ///
+    /// # Additional Information
+    ///
+    /// Passing `0` for either date leaves that side of the mint window open. Once an open
+    /// edition's end date has passed its supply is final, so its dates can no longer be changed.
+    ///
     /// # Arguments
     ///
-    /// * `start`: [u64] - The start date to set.
-    /// * `end`: [u64] - The end date to set.
+    /// * `start`: [u64] - The start date to set, or `0` for no start date.
+    /// * `end`: [u64] - The end date to set, or `0` for no end date.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the metadata role.
+    /// * When both dates are set and `start` is not before `end`.
+    /// * When the contract is an open edition whose end date has passed.
     ///
     /// # Number of Storage Accesses
     ///
+    /// * Reads: `1`
     /// * Writes: `2`
     ///
     /// # Examples
@@ -1145,9 +2332,14 @@ impl SetMintMetadata for Contract {
     ///     assert_eq!(mint_abi.end_date(), Some(2000));
     /// }
     /// ```
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_dates(start: u64, end: u64) {
-        only_owner();
+        only_role(METADATA_ROLE);
+        _require_valid_mint_window(start, end);
+        if OPEN_EDITION {
+            let end_date = storage.end_date.try_read().unwrap_or(0);
+            require(end_date == 0 || timestamp() <= end_date, SetError::MintWindowEnded);
+        }
         storage.start_date.write(start);
         storage.end_date.write(end);
         log(SetMintDatesEvent{
@@ -1162,6 +2354,10 @@ impl SetMintMetadata for Contract {
     ///
     /// * `root`: [b256] - The Merkle root to set.
     ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the minter role.
+    ///
     /// # Number of Storage Accesses
     ///
     /// * Writes: `1`
@@ -1179,7 +2375,7 @@ impl SetMintMetadata for Contract {
     /// ```
     #[storage(write)]
     fn set_merkle_root(root: b256) {
-        only_owner();
+        only_role(MINTER_ROLE);
         storage.merkle_root.write(root);
     }
 
@@ -1242,6 +2438,10 @@ impl SetMintMetadata for Contract {
     /// * `root`: [b256] - The Merkle root to set.
     /// * `uri`: [String] - The Merkle URI to set.
     ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner and does not have the minter role.
+    ///
     /// # Number of Storage Accesses
     ///
     /// * Writes: `2`
@@ -1260,7 +2460,7 @@ impl SetMintMetadata for Contract {
     /// ```
     #[storage(write)]
     fn set_merkle(root: b256, uri: String) {
-        only_owner();
+        only_role(MINTER_ROLE);
         storage.merkle_root.write(root);
         storage.merkle_uri.write_slice(uri);
         log(SetMerkleRootEvent{
@@ -1271,13 +2471,20 @@ impl SetMintMetadata for Contract {
 
     /// Returns the maximum supply of tokens that can be minted.
     ///
+    /// # Additional Information
+    ///
+    /// The maximum supply starts at `MAX_SUPPLY` and may have been reduced by the owner.
+    /// Open editions have no maximum supply while the mint window is open. Once the end date
+    /// has passed, the supply is locked and the number of minted tokens is returned. Open
+    /// editions without an end date never lock their supply.
+    ///
     /// # Returns
     ///
-    /// * [Option<u64>] - The maximum supply of tokens.
+    /// * [Option<u64>] - The maximum supply of tokens, or `None` for an open edition that is still minting.
     ///
     /// # Number of Storage Accesses
     ///
-    /// * Reads: `1`
+    /// * Reads: `2`
     ///
     /// # Examples
     ///
@@ -1290,8 +2497,18 @@ impl SetMintMetadata for Contract {
     ///     assert(max_supply.is_some());
     /// }
     /// ```
+    #[storage(read)]
     fn max_supply() -> Option<u64> {
-        Some(MAX_SUPPLY)
+        if !OPEN_EDITION {
+            return Some(_max_supply(storage.max_supply));
+        }
+
+        let end_date = storage.end_date.try_read().unwrap_or(0);
+        if end_date != 0 && timestamp() > end_date {
+            Some(storage.total_assets.try_read().unwrap_or(0))
+        } else {
+            None
+        }
     }
 }
 
@@ -1300,7 +2517,7 @@ impl Pausable for Contract {
     ///
     /// # Reverts
     ///
-    /// * When the caller is not the contract owner.
+    /// * When the caller is not the contract owner and does not have the pauser role.
     ///
     /// # Number of Storage Accesses
     ///
@@ -1319,7 +2536,7 @@ impl Pausable for Contract {
     /// ```
     #[storage(write)]
     fn pause() {
-        only_owner();
+        only_role(PAUSER_ROLE);
         _pause();
     }
 
@@ -1352,7 +2569,7 @@ impl Pausable for Contract {
     ///
     /// # Reverts
     ///
-    /// * When the caller is not the contract owner.
+    /// * When the caller is not the contract owner and does not have the pauser role.
     ///
     /// # Number of Storage Accesses
     ///
@@ -1371,7 +2588,7 @@ impl Pausable for Contract {
     /// ```
     #[storage(write)]
     fn unpause() {
-        only_owner();
+        only_role(PAUSER_ROLE);
         _unpause();
     }
 }
@@ -1385,7 +2602,10 @@ impl Props721Edition for Contract {
     ///
     /// # Reverts
     ///
+    /// * When `REGISTRY_CONTRACT_ID` is not set or the caller is not the registry.
+    /// * When `BUILDER_REVENUE_SHARE_PERCENTAGE` and `AFFILIATE_FEE_PERCENTAGE` add up to more than 100.
     /// * When ownership has been set before.
+    /// * When both dates are set and `start_date` is not before `end_date`.
     ///
     /// # Number of Storage Acesses
     ///
@@ -1408,6 +2628,11 @@ impl Props721Edition for Contract {
     /// }
     #[storage(read, write)]
     fn constructor(owner: Identity, name: String, symbol: String, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, start_date: u64, end_date: u64) {
+        only_registry(REGISTRY_CONTRACT_ID);
+        require(
+            BUILDER_REVENUE_SHARE_PERCENTAGE + AFFILIATE_FEE_PERCENTAGE <= 100,
+            SetError::InvalidFeePercentages,
+        );
         initialize_ownership(owner);
 
         storage.name.write_slice(name);
@@ -1423,6 +2648,8 @@ impl Props721Edition for Contract {
             i += 1;
         }
 
+        _require_valid_mint_window(start_date, end_date);
+
         storage.price.write(price);
         storage.start_date.write(start_date);
         storage.end_date.write(end_date);
@@ -1,7 +1,8 @@
 use crate::utils::{
-    interface::{airdrop, constructor, total_assets, total_supply},
-    setup::{defaults, get_wallet_balance, setup, default_name, default_metadata_keys, default_metadata_values, default_symbol, default_price, default_end_date, default_start_date},
+    interface::{airdrop, constructor, set_dates, total_assets, total_supply},
+    setup::{defaults, get_wallet_balance, setup, deploy_open_edition, default_name, default_metadata_keys, default_metadata_values, default_symbol, default_price, default_end_date, default_start_date},
 };
+use tai64::Tai64;
 
 mod success {
 
@@ -41,7 +42,7 @@ mod revert {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "NotOwner")]
+    #[should_panic(expected = "MissingRole")]
     async fn when_not_owner() {
         let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
         let (
@@ -60,4 +61,27 @@ mod revert {
         // Attempt to mint from a non-owner wallet
         airdrop(&instance_2, other_identity, 1).await;
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    #[should_panic(expected = "SupplyLocked")]
+    async fn when_open_edition_has_ended() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = deploy_open_edition().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        let current_time = Tai64::now().0;
+        set_dates(&instance_1, current_time - 7200, current_time - 3600).await;
+
+        airdrop(&instance_1, other_identity, 1).await;
+    }
+}
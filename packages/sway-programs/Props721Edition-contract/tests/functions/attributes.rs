@@ -0,0 +1,67 @@
+use crate::utils::{
+    interface::{airdrop, attributes, constructor, set_attributes},
+    setup::{defaults, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_metadata_keys, default_metadata_values, Metadata},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_empty_when_not_set() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        assert_eq!(attributes(&instance_1, asset_id_1, 0, 10).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn paginates_attributes() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+        airdrop(&instance_1, other_identity, 1).await;
+
+        set_attributes(
+            &instance_1,
+            asset_id_1,
+            vec![String::from("class"), String::from("level"), String::from("guild")],
+            vec![Metadata::String(String::from("Warrior")), Metadata::Int(5), Metadata::String(String::from("Props"))],
+        ).await;
+
+        assert_eq!(
+            attributes(&instance_1, asset_id_1, 0, 2).await,
+            vec![
+                (String::from("class"), Metadata::String(String::from("Warrior"))),
+                (String::from("level"), Metadata::Int(5)),
+            ]
+        );
+        assert_eq!(
+            attributes(&instance_1, asset_id_1, 2, 2).await,
+            vec![(String::from("guild"), Metadata::String(String::from("Props")))]
+        );
+        assert_eq!(attributes(&instance_1, asset_id_1, 3, 2).await, vec![]);
+    }
+}
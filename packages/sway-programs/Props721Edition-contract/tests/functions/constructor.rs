@@ -1,6 +1,6 @@
 use crate::utils::{
     interface::{constructor, owner, metadata, price, metadata_keys},
-    setup::{defaults, setup, default_start_date, default_end_date, deploy_edition_with_builder_fee, default_name, default_price, default_symbol, default_metadata_keys, default_metadata_values, Metadata, State},
+    setup::{defaults, deploy_without_registry, setup, default_start_date, default_end_date, deploy_edition_with_builder_fee, default_name, default_price, default_symbol, default_metadata_keys, default_metadata_values, Metadata, State},
 };
 
 mod success {
@@ -73,4 +73,75 @@ mod success {
             Some(default_price())
         );
     }
-}
\ No newline at end of file
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidMintWindow")]
+    async fn when_start_date_is_not_before_end_date() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_end_date(), default_start_date()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRegistry")]
+    async fn when_caller_is_not_registry() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        instance_1
+            .methods()
+            .constructor(owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date())
+            .call()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRegistry")]
+    async fn when_registry_is_not_set() {
+        let (owner_wallet, other_wallet, id, _instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        let instance = deploy_without_registry(&owner_wallet).await;
+
+        instance
+            .methods()
+            .constructor(owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date())
+            .call()
+            .await
+            .unwrap();
+    }
+}
@@ -1,7 +1,9 @@
 use crate::utils::{
-    interface::{max_supply, constructor},
-    setup::{defaults, setup, default_start_date, default_end_date, default_name, default_price, default_symbol, default_metadata_keys, default_metadata_values},
+    interface::{max_supply, constructor, mint, skip_time},
+    setup::{defaults, setup, deploy_open_edition, default_start_date, default_end_date, default_name, default_price, default_symbol, default_metadata_keys, default_metadata_values},
 };
+use chrono::Duration;
+use tai64::Tai64;
 
 mod success {
     use super::*;
@@ -27,4 +29,48 @@ mod success {
         assert!(result.is_some());
         assert_eq!(result.unwrap(), 3); // Assuming the max supply is 3, adjust if different
     }
+
+    #[tokio::test]
+    async fn returns_none_for_open_edition() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = deploy_open_edition().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(max_supply(&instance_1).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_total_assets_for_open_edition_after_end_date() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_open_edition().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        let end_date = Tai64::now().0 + 3600;
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), end_date).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+        assert_eq!(max_supply(&instance_1).await, None);
+
+        skip_time(&instance_1, Duration::hours(2)).await;
+
+        assert_eq!(max_supply(&instance_1).await, Some(1));
+    }
 }
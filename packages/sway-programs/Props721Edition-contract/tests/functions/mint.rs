@@ -1,6 +1,6 @@
 use crate::utils::{
     interface::{burn, constructor, mint, pause, total_assets, total_supply, set_fee, fee, fee_constructor, set_price, set_merkle_root},
-    setup::{defaults, get_wallet_balance, setup, deploy_edition_with_builder_fee, default_name, default_metadata_keys, default_metadata_values, default_symbol, default_price, default_end_date, default_start_date},
+    setup::{defaults, get_wallet_balance, registry_contract_id, setup, PropsRegistry, deploy_edition_with_builder_fee, deploy_open_edition, default_name, default_metadata_keys, default_metadata_values, default_symbol, default_price, default_end_date, default_start_date},
 };
 use fuels::{
     prelude::*,
@@ -493,6 +493,52 @@ mod success {
         assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(1));
         assert_eq!(total_assets(&instance_1).await, 3);
     }
+
+    #[tokio::test]
+    async fn mints_past_max_supply_in_open_edition() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_open_edition().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            sub_id_2,
+            sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet);
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+        mint(&instance_1, other_identity, sub_id_2, 1, 0, fee_id, None, None, None, None, None).await;
+        mint(&instance_1, other_identity, sub_id_3, 1, 0, fee_id, None, None, None, None, None).await;
+        mint(&instance_1, other_identity, Bits256([4u8; 32]), 1, 0, fee_id, None, None, None, None, None).await;
+
+        assert_eq!(total_assets(&instance_1).await, 4);
+    }
+
+    #[tokio::test]
+    async fn mints_without_start_and_end_dates() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = setup().await;
+        let (
+            asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), 0, 0).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 1);
+        assert_eq!(total_assets(&instance_1).await, 1);
+    }
 }
 
 mod revert {
@@ -521,6 +567,34 @@ mod revert {
         mint(&instance_2, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
     }
 
+    #[tokio::test]
+    #[should_panic(expected = "ProtocolPaused")]
+    async fn when_protocol_paused() {
+        let registry_id = registry_contract_id();
+        let (owner_wallet, other_wallet, id, _instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet);
+
+        let registry = PropsRegistry::new(registry_id, owner_wallet);
+        registry.methods().set_protocol_paused(true).call().await.unwrap();
+
+        instance_2
+            .methods()
+            .mint(other_identity, sub_id_1, 1, None, None, None, None, None)
+            .with_contract_ids(&[registry_id.into()])
+            .call()
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     #[should_panic(expected = "MaxNFTsMinted")]
     async fn when_max_supply_reached() {
@@ -0,0 +1,231 @@
+use crate::utils::{
+    interface::{constructor, last_mint_height, last_mint_time, max_mints_per_block, max_per_transaction, mint, mint_cooldown, mint_cooldown_blocks},
+    setup::{defaults, setup, deploy_with_mint_cooldown_blocks, deploy_with_mint_limits, default_start_date, default_end_date, default_name, default_price, default_symbol, default_metadata_keys, default_metadata_values},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_no_limits_by_default() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(max_per_transaction(&instance_1).await, None);
+        assert_eq!(mint_cooldown(&instance_1).await, None);
+        assert_eq!(mint_cooldown_blocks(&instance_1).await, None);
+        assert_eq!(max_mints_per_block(&instance_1).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_configured_limits() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = deploy_with_mint_limits(2, 3600, 5).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            _sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(max_per_transaction(&instance_1).await, Some(2));
+        assert_eq!(mint_cooldown(&instance_1).await, Some(3600));
+        assert_eq!(max_mints_per_block(&instance_1).await, Some(5));
+    }
+
+    #[tokio::test]
+    async fn tracks_last_mint_time() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_with_mint_limits(0, 3600, 0).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(last_mint_time(&instance_1, owner_identity).await, None);
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+
+        // The cooldown tracks the wallet that minted, not the recipient
+        assert!(last_mint_time(&instance_1, owner_identity).await.is_some());
+        assert_eq!(last_mint_time(&instance_1, other_identity).await, None);
+    }
+
+    #[tokio::test]
+    async fn applies_cooldown_per_minter() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2, fee_id, _fee_instance_1) = deploy_with_mint_limits(0, 3600, 0).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+        mint(&instance_2, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+
+        assert!(last_mint_time(&instance_1, other_identity).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn tracks_last_mint_height() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_with_mint_cooldown_blocks(100).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        assert_eq!(mint_cooldown_blocks(&instance_1).await, Some(100));
+        assert_eq!(last_mint_height(&instance_1, owner_identity).await, None);
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+
+        assert!(last_mint_height(&instance_1, owner_identity).await.is_some());
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ExceededMaxPerTransaction")]
+    async fn when_exceeding_max_per_transaction() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_with_mint_limits(1, 0, 0).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 2, 0, fee_id, None, None, None, None, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MintCooldownActive")]
+    async fn when_minting_during_cooldown() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_with_mint_limits(0, 3600, 0).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ExceededMaxMintsPerBlock")]
+    async fn when_exceeding_max_mints_per_block() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_with_mint_limits(0, 0, 2).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 3, 0, fee_id, None, None, None, None, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MintCooldownActive")]
+    async fn when_minting_to_another_recipient_during_cooldown() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_with_mint_limits(0, 3600, 0).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+        mint(&instance_1, owner_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MintCooldownActive")]
+    async fn when_minting_during_block_cooldown() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2, fee_id, _fee_instance_1) = deploy_with_mint_cooldown_blocks(100).await;
+        let (
+            _asset_id_1,
+            _asset_id_2,
+            _asset_id_3,
+            sub_id_1,
+            _sub_id_2,
+            _sub_id_3,
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity, default_name(), default_symbol(), default_metadata_keys(), default_metadata_values(), default_price(), default_start_date(), default_end_date()).await;
+
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+        mint(&instance_1, other_identity, sub_id_1, 1, 0, fee_id, None, None, None, None, None).await;
+    }
+}
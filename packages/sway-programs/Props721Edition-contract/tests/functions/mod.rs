@@ -19,4 +19,18 @@ mod start_date;
 mod end_date;
 mod set_dates;
 mod max_supply;
-
+mod set_attributes;
+mod attributes;
+mod set_name;
+mod set_symbol;
+mod freeze_metadata;
+mod transfer_ownership;
+mod accept_ownership;
+mod renounce_ownership;
+mod grant_role;
+mod revoke_role;
+mod reduce_max_supply;
+mod lock_supply;
+mod mint_limits;
+mod sweep;
+mod quote_mint;
@@ -60,7 +60,7 @@ mod revert {
     use super::*;
 
     #[tokio::test]
-    #[should_panic(expected = "NotOwner")]
+    #[should_panic(expected = "MissingRole")]
     async fn when_not_owner() {
         let (owner_wallet, other_wallet, id, instance_1, instance_2, _fee_id, _fee_instance_1) = setup().await;
         let (
@@ -80,7 +80,7 @@ mod revert {
     }
 
     #[tokio::test]
-    #[should_panic(expected = "NotOwner")]
+    #[should_panic(expected = "MissingRole")]
     async fn when_not_initialized() {
         let (owner_wallet, other_wallet, id, instance_1, _instance_2, _fee_id, _fee_instance_1) = setup().await;
         let (
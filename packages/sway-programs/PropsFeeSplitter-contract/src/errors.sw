@@ -2,10 +2,19 @@ library;
 
 pub enum SetError {
     ValueAlreadySet: (),
+    LengthMismatch: (),
+    ZeroShares: (),
+    DuplicatePayee: (),
+    PayeeNotFound: (),
+    InvalidBasisPoints: (),
+    InvalidFeeBounds: (),
+    FeeExceedsMaximum: (),
+    NoPendingFee: (),
+    FeeChangeTimelocked: (),
 }
 
 pub enum DistributionError {
     CanNotSendZero: (),
-    AssetNotBaseId: (),
     AmountIsZero: (),
+    UnsupportedAsset: (),
 }
@@ -7,11 +7,6 @@ use errors::{SetError, DistributionError};
 use interface::{Constructor};
 use standards::{src5::{SRC5, State},};
 use sway_libs::{
-    ownership::{
-        _owner,
-        initialize_ownership,
-        only_owner,
-    },
     pausable::{
         _is_paused,
         _pause,
@@ -21,25 +16,83 @@ use sway_libs::{
     },
 };
 use std::{
-    hash::Hash,
+    hash::{Hash, sha256},
     asset::{transfer},
     call_frames::msg_asset_id,
-    context::msg_amount,
+    context::{msg_amount, this_balance},
     address::Address,
     auth::msg_sender,
     identity::Identity,
     storage::storage_vec::*,
+    string::String,
+    block::timestamp,
 };
 use std::logging::log;
-use libraries::{PropsFeeSplitter};
+use libraries::{
+    DistributionThresholdSetEvent,
+    FeeChangeQueuedEvent,
+    FeeConfig,
+    FeeOverride,
+    FeeOverrideChangeQueuedEvent,
+    FeeOverrideSetEvent,
+    FeeSetEvent,
+    FundsReceivedEvent,
+    FundsReleasedEvent,
+    Ownable,
+    PendingFee,
+    PendingFeeOverride,
+    PendingRegistry,
+    PropsFeeSplitter,
+    PropsRegistry,
+    RegistryChangeQueuedEvent,
+    RegistrySetEvent,
+    SharesSetEvent,
+    Sweep,
+};
+use libraries::ownership::{_accept_ownership, _renounce_ownership, _transfer_ownership};
+use sway_libs::ownership::{_owner, initialize_ownership, only_owner};
+use libraries::sweep::_sweep;
+
+/// The largest flat fee, and fee minimum, that can be charged per token in any asset.
+const MAX_FLAT_FEE: u64 = 1_000_000;
+
+/// The largest share of the creator price that can be charged, in basis points.
+const MAX_FEE_BASIS_POINTS: u64 = 1_000;
+
+/// The most payees `receive_funds` pays out automatically, keeping the cost of a mint bounded.
+const MAX_AUTO_DISTRIBUTION_PAYEES: u64 = 10;
+
+/// The number of seconds a fee increase is announced before it can be applied.
+const FEE_CHANGE_DELAY: u64 = 604_800;
 
 storage {
-    /// The fee amount for the contract.
+    /// The fee configuration for the contract in each payment asset.
     ///
     /// # Description
-    /// This storage variable holds the fee amount that is set by the contract owner.
-    /// It is used to determine the fee that will be applied to transactions.
-    fee: u64 = 0,
+    /// This storage map holds the fee configurations that are set by the contract owner, keyed by asset.
+    /// It is used to determine the fee that will be applied to transactions paid in that asset.
+    fee_configs: StorageMap<AssetId, FeeConfig> = StorageMap {},
+
+    /// The fee increases waiting for `FEE_CHANGE_DELAY` to pass, keyed by asset.
+    pending_fees: StorageMap<AssetId, PendingFee> = StorageMap {},
+
+    /// The custom base asset fees of partner contracts.
+    contract_fee_overrides: StorageMap<ContractId, FeeOverride> = StorageMap {},
+
+    /// The contract override changes waiting for `FEE_CHANGE_DELAY` to pass.
+    pending_fee_overrides: StorageMap<ContractId, PendingFeeOverride> = StorageMap {},
+
+    /// The custom base asset fees of registry categories, keyed by the hash of the category.
+    category_fee_overrides: StorageMap<b256, FeeOverride> = StorageMap {},
+
+    /// The category override changes waiting for `FEE_CHANGE_DELAY` to pass, keyed by the hash of the category.
+    pending_category_fee_overrides: StorageMap<b256, PendingFeeOverride> = StorageMap {},
+
+    /// The registry used to look up the category of a contract.
+    registry: Option<ContractId> = None,
+
+    /// The registry change waiting for `FEE_CHANGE_DELAY` to pass.
+    pending_registry: Option<PendingRegistry> = None,
 
     /// The total number of shares.
     ///
@@ -48,12 +101,346 @@ storage {
     /// to recipients. It is used to calculate the distribution of funds.
     total_shares: u64 = 0,
 
-    /// The shares allocated to each recipient.
+    /// The identities entitled to a portion of the received funds.
+    ///
+    /// # Description
+    /// This storage vector holds every payee exactly once, in no particular order.
+    payees: StorageVec<Identity> = StorageVec {},
+
+    /// The shares allocated to each payee.
     ///
     /// # Description
-    /// This storage map holds the shares allocated to each recipient, identified by their address.
-    /// The key is the recipient's address, and the value is the number of shares allocated to them.
-    shares: StorageVec<(Identity, u64)> = StorageVec {},
+    /// This storage map holds the shares allocated to each payee. Payees always hold at least one share,
+    /// so an identity without shares is not a payee.
+    shares: StorageMap<Identity, u64> = StorageMap {},
+
+    /// The position of each payee in `payees`.
+    payee_index: StorageMap<Identity, u64> = StorageMap {},
+
+    /// The total amount of each asset received through `receive_funds`.
+    ///
+    /// # Description
+    /// Only these funds are owed to payees. Anything else the contract holds can be swept by the owner.
+    total_received: StorageMap<AssetId, u64> = StorageMap {},
+
+    /// Every asset received through `receive_funds`, in order of first receipt.
+    received_assets: StorageVec<AssetId> = StorageVec {},
+
+    /// The part of `total_received` already credited to payees under previous shares.
+    ///
+    /// # Description
+    /// Only funds received after this point are split by the current shares.
+    credited_received: StorageMap<AssetId, u64> = StorageMap {},
+
+    /// The funds each payee earned under previous shares and has not been released yet.
+    credited: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+
+    /// The amount each payee has been released of its earnings under the current shares.
+    released_under_shares: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+
+    /// The total amount of each asset released to payees.
+    total_released: StorageMap<AssetId, u64> = StorageMap {},
+
+    /// The amount of each asset released to each payee.
+    released: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+
+    /// The minimum undistributed balance of each asset at which `receive_funds` pays out all payees.
+    ///
+    /// # Description
+    /// Zero disables automatic distribution, leaving payees to call `release`.
+    distribution_thresholds: StorageMap<AssetId, u64> = StorageMap {},
+
+    /// The identity proposed as new owner, until it accepts.
+    pending_owner: Option<Identity> = None,
+}
+
+/// Returns the shares held by `payee`, which is zero for identities that are not payees.
+#[storage(read)]
+fn _share(payee: Identity) -> u64 {
+    storage.shares.get(payee).try_read().unwrap_or(0)
+}
+
+#[storage(read, write)]
+fn _add_payee(payee: Identity, share: u64) {
+    require(share > 0, SetError::ZeroShares);
+    require(_share(payee) == 0, SetError::DuplicatePayee);
+
+    storage.payee_index.insert(payee, storage.payees.len());
+    storage.payees.push(payee);
+    storage.shares.insert(payee, share);
+    let total_shares = storage.total_shares.try_read().unwrap_or(0) + share;
+    storage.total_shares.write(total_shares);
+
+    log(SharesSetEvent { payee, shares: share, total_shares });
+}
+
+/// Removes `payee` by moving the last payee into its position.
+#[storage(read, write)]
+fn _remove_payee(payee: Identity) {
+    let share = _share(payee);
+    require(share > 0, SetError::PayeeNotFound);
+
+    let index = storage.payee_index.get(payee).read();
+    let _ = storage.payees.swap_remove(index);
+    if index < storage.payees.len() {
+        storage.payee_index.insert(storage.payees.get(index).unwrap().read(), index);
+    }
+    let _ = storage.payee_index.remove(payee);
+    let _ = storage.shares.remove(payee);
+    let total_shares = storage.total_shares.read() - share;
+    storage.total_shares.write(total_shares);
+
+    log(SharesSetEvent { payee, shares: 0, total_shares });
+}
+
+#[storage(read)]
+fn _fee_config(asset: AssetId) -> FeeConfig {
+    storage.fee_configs.get(asset).try_read().unwrap_or(FeeConfig {
+        flat: 0,
+        basis_points: 0,
+        min: 0,
+        max: 0,
+    })
+}
+
+fn _require_valid_fee_config(config: FeeConfig) {
+    require(config.basis_points <= MAX_FEE_BASIS_POINTS, SetError::InvalidBasisPoints);
+    require(config.flat <= MAX_FLAT_FEE && config.min <= MAX_FLAT_FEE, SetError::FeeExceedsMaximum);
+    require(config.max == 0 || config.min <= config.max, SetError::InvalidFeeBounds);
+}
+
+/// Returns `fee_override` if it has not expired yet.
+fn _active(fee_override: Option<FeeOverride>) -> Option<FeeOverride> {
+    match fee_override {
+        Some(fee_override) => {
+            if fee_override.expires_at == 0 || timestamp() < fee_override.expires_at {
+                Some(fee_override)
+            } else {
+                None
+            }
+        },
+        None => None,
+    }
+}
+
+/// Returns the configuration of `fee_override`, or `fallback` if it is missing or expired.
+fn _config_or(fee_override: Option<FeeOverride>, fallback: FeeConfig) -> FeeConfig {
+    match _active(fee_override) {
+        Some(fee_override) => fee_override.config,
+        None => fallback,
+    }
+}
+
+/// Returns whether replacing the override `current` with `next` can raise the fee of a contract
+/// that pays `fallback` without it.
+///
+/// An override that expires sooner than the one it replaces raises the fee once it expires.
+fn _is_override_raised_by(current: Option<FeeOverride>, next: Option<FeeOverride>, fallback: FeeConfig) -> bool {
+    let current_config = _config_or(current, fallback);
+    if current_config.is_raised_by(_config_or(next, fallback)) {
+        return true;
+    }
+
+    if let Some(current) = _active(current) {
+        if let Some(next) = _active(next) {
+            let expires_sooner = next.expires_at != 0 && (current.expires_at == 0 || next.expires_at < current.expires_at);
+            return expires_sooner && current_config.is_raised_by(fallback);
+        }
+    }
+
+    false
+}
+
+/// Returns the base asset fee configuration that applies to `contract_id` when it has no override.
+#[storage(read)]
+fn _category_fee_config(contract_id: ContractId) -> FeeConfig {
+    if let Some(registry) = storage.registry.try_read().unwrap_or(None) {
+        if let Some(category) = abi(PropsRegistry, registry.bits()).category(contract_id) {
+            if let Some(fee_override) = _active(storage.category_fee_overrides.get(sha256(category)).try_read()) {
+                return fee_override.config;
+            }
+        }
+    }
+
+    _fee_config(AssetId::base())
+}
+
+/// Returns the base asset fee configuration that applies to `contract_id`.
+///
+/// A contract override wins over an override of the contract's registry category, which wins over
+/// the default configuration.
+#[storage(read)]
+fn _resolved_fee_config(contract_id: ContractId) -> FeeConfig {
+    if let Some(fee_override) = _active(storage.contract_fee_overrides.get(contract_id).try_read()) {
+        return fee_override.config;
+    }
+
+    _category_fee_config(contract_id)
+}
+
+#[storage(write)]
+fn _set_fee_override(contract_id: ContractId, fee_override: Option<FeeOverride>) {
+    match fee_override {
+        Some(fee_override) => {
+            storage.contract_fee_overrides.insert(contract_id, fee_override);
+        },
+        None => {
+            let _ = storage.contract_fee_overrides.remove(contract_id);
+        },
+    }
+
+    log(FeeOverrideSetEvent { contract_id: Some(contract_id), category: None, fee_override });
+}
+
+#[storage(write)]
+fn _set_category_fee_override(category: String, fee_override: Option<FeeOverride>) {
+    match fee_override {
+        Some(fee_override) => {
+            storage.category_fee_overrides.insert(sha256(category), fee_override);
+        },
+        None => {
+            let _ = storage.category_fee_overrides.remove(sha256(category));
+        },
+    }
+
+    log(FeeOverrideSetEvent { contract_id: None, category: Some(category), fee_override });
+}
+
+#[storage(write)]
+fn _set_fee_config(asset: AssetId, config: FeeConfig) {
+    storage.fee_configs.insert(asset, config);
+
+    log(FeeSetEvent { asset, config });
+}
+
+/// Changes the fee configuration for `asset`.
+///
+/// Changes that can raise the fee of a mint are queued for `FEE_CHANGE_DELAY` seconds. Other changes
+/// apply immediately and cancel any queued increase.
+#[storage(read, write)]
+fn _change_fee_config(asset: AssetId, config: FeeConfig) {
+    _require_valid_fee_config(config);
+
+    if _fee_config(asset).is_raised_by(config) {
+        let effective_at = timestamp() + FEE_CHANGE_DELAY;
+        storage.pending_fees.insert(asset, PendingFee { config, effective_at });
+
+        log(FeeChangeQueuedEvent { asset, config, effective_at });
+    } else {
+        let _ = storage.pending_fees.remove(asset);
+        _set_fee_config(asset, config);
+    }
+}
+
+/// Changes the flat per-token part of the fee configuration for `asset`.
+#[storage(read, write)]
+fn _change_flat_fee(asset: AssetId, fee: u64) {
+    let mut config = _fee_config(asset);
+    config.flat = fee;
+    _change_fee_config(asset, config);
+}
+
+/// Returns the amount of `asset` received through `receive_funds` that has not been released yet.
+#[storage(read)]
+fn _unreleased(asset: AssetId) -> u64 {
+    let total_received = storage.total_received.get(asset).try_read().unwrap_or(0);
+    let total_released = storage.total_released.get(asset).try_read().unwrap_or(0);
+    total_received - total_released
+}
+
+/// Returns the pro-rata share of `payee` in the funds received since the shares last changed.
+#[storage(read)]
+fn _entitled(payee: Identity, asset: AssetId) -> u64 {
+    let total_shares = storage.total_shares.try_read().unwrap_or(0);
+    let share = _share(payee);
+    if total_shares == 0 || share == 0 {
+        return 0;
+    }
+
+    let total_received = storage.total_received.get(asset).try_read().unwrap_or(0);
+    let credited_received = storage.credited_received.get(asset).try_read().unwrap_or(0);
+    let entitled = ((total_received - credited_received).as_u256() * share.as_u256()) / total_shares.as_u256();
+    <u64 as TryFrom<u256>>::try_from(entitled).unwrap()
+}
+
+/// Returns the amount of `asset` that `payee` can currently withdraw.
+///
+/// A payee is owed what it earned under previous shares, plus its pro-rata share of the funds
+/// received since, minus what it has already been released of that share.
+#[storage(read)]
+fn _releasable(payee: Identity, asset: AssetId) -> u64 {
+    let credited = storage.credited.get((payee, asset)).try_read().unwrap_or(0);
+    let released = storage.released_under_shares.get((payee, asset)).try_read().unwrap_or(0);
+    credited + _entitled(payee, asset) - released
+}
+
+/// Credits every payee with what it earned under the current shares, so that a share change only
+/// applies to funds received afterwards.
+///
+/// Rounding dust is not credited and is split by the new shares.
+#[storage(read, write)]
+fn _checkpoint_shares() {
+    let mut i = 0;
+    while i < storage.received_assets.len() {
+        let asset = storage.received_assets.get(i).unwrap().read();
+        let mut earned = 0;
+
+        let mut j = 0;
+        while j < storage.payees.len() {
+            let payee = storage.payees.get(j).unwrap().read();
+            let entitled = _entitled(payee, asset);
+            let released = storage.released_under_shares.get((payee, asset)).try_read().unwrap_or(0);
+            let credited = storage.credited.get((payee, asset)).try_read().unwrap_or(0);
+            storage.credited.insert((payee, asset), credited + entitled - released);
+            let _ = storage.released_under_shares.remove((payee, asset));
+            earned += entitled;
+            j += 1;
+        }
+
+        let credited_received = storage.credited_received.get(asset).try_read().unwrap_or(0);
+        storage.credited_received.insert(asset, credited_received + earned);
+        i += 1;
+    }
+}
+
+/// Returns the balance of `asset` that is not owed to payees.
+#[storage(read)]
+fn _sweepable(asset: AssetId) -> u64 {
+    let balance = this_balance(asset);
+    let unreleased = _unreleased(asset);
+    if balance > unreleased { balance - unreleased } else { 0 }
+}
+
+/// Transfers `amount`, which must be everything `payee` is owed in `asset`, to `payee`.
+#[storage(read, write)]
+fn _release(payee: Identity, asset: AssetId, amount: u64) {
+    let _ = storage.credited.remove((payee, asset));
+    storage.released_under_shares.insert((payee, asset), _entitled(payee, asset));
+
+    let released = storage.released.get((payee, asset)).try_read().unwrap_or(0);
+    storage.released.insert((payee, asset), released + amount);
+    let total_released = storage.total_released.get(asset).try_read().unwrap_or(0);
+    storage.total_released.insert(asset, total_released + amount);
+
+    transfer(payee, asset, amount);
+
+    log(FundsReleasedEvent { payee, asset, amount });
+}
+
+/// Releases the pending funds in `asset` of every payee.
+///
+/// Rounding dust stays in the contract and is included in the next distribution.
+#[storage(read, write)]
+fn _distribute(asset: AssetId) {
+    let mut i = 0;
+    while i < storage.payees.len() {
+        let payee = storage.payees.get(i).unwrap().read();
+        let amount = _releasable(payee, asset);
+        if amount > 0 {
+            _release(payee, asset, amount);
+        }
+        i += 1;
+    }
 }
 
 impl SRC5 for Contract {
@@ -88,24 +475,156 @@ impl SRC5 for Contract {
     }
 }
 
+impl Ownable for Contract {
+    /// Proposes a new owner for the contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Ownership only changes once `new_owner` calls `accept_ownership`. Proposing another
+    /// identity replaces the pending proposal.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner`: [Identity] - The identity proposed as new owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    ///
+    /// fn foo(contract_id: ContractId, new_owner: Identity) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.transfer_ownership(new_owner);
+    ///     assert(ownable_abi.pending_owner() == Some(new_owner));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        _transfer_ownership(new_owner, storage.pending_owner);
+    }
+
+    /// Accepts a pending ownership transfer, making the caller the owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the pending owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    /// use standards::src5::{SRC5, State};
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.accept_ownership();
+    ///     assert(abi(SRC5, contract_id).owner() == State::Initialized(msg_sender().unwrap()));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn accept_ownership() {
+        _accept_ownership(storage.pending_owner);
+    }
+
+    /// Permanently renounces ownership of the contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Any pending ownership transfer is cancelled. Owner-only functions can no longer be called afterwards.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    /// use standards::src5::{SRC5, State};
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.renounce_ownership();
+    ///     assert(abi(SRC5, contract_id).owner() == State::Revoked);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn renounce_ownership() {
+        _renounce_ownership(storage.pending_owner);
+    }
+
+    /// Returns the identity that has been proposed as new owner.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Identity>] - The pending owner, or `None` if no transfer is pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     assert(ownable_abi.pending_owner().is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity> {
+        storage.pending_owner.read()
+    }
+}
+
 impl PropsFeeSplitter for Contract {
-    /// Sets the fee amount for the contract.
+    /// Sets the flat fee charged per token in the base asset.
+    ///
+    /// # Additional Information
+    ///
+    /// Increases are queued and only apply once `apply_fee` is called after `FEE_CHANGE_DELAY` seconds.
     ///
     /// # Arguments
     ///
     /// * `fee`: [u64] - The fee amount to be set by the contract owner.
     ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When `fee` is greater than `MAX_FLAT_FEE`.
+    ///
     /// # Storage Accesses
     ///
-    /// * Reads: `1`
-    /// * Writes: `1`
+    /// * Reads: `2`
+    /// * Writes: `2`
     #[storage(read, write)]
     fn set_fee(fee: u64) {
         only_owner();
-        storage.fee.write(fee);
+        _change_flat_fee(AssetId::base(), fee);
     }
 
-    /// Returns the current fee amount.
+    /// Returns the flat fee charged per token in the base asset.
     ///
     /// # Returns
     ///
@@ -116,127 +635,904 @@ impl PropsFeeSplitter for Contract {
     /// * Reads: `1`
     #[storage(read)]
     fn fee() -> Option<u64> {
-        Some(storage.fee.try_read().unwrap_or(0))
+        Some(_fee_config(AssetId::base()).flat)
     }
 
-    /// Sets the shares for recipients.
+    /// Sets the flat fee charged per token for mints paid in the given asset.
+    ///
+    /// # Additional Information
+    ///
+    /// Increases are queued and only apply once `apply_fee` is called after `FEE_CHANGE_DELAY` seconds.
     ///
     /// # Arguments
     ///
-    /// * `recipients`: [Vec<Address>] - A vector of recipient addresses.
-    /// * `shares`: [Vec<u64>] - A vector of shares corresponding to each recipient.
+    /// * `asset`: [AssetId] - The payment asset.
+    /// * `fee`: [u64] - The fee amount, denominated in `asset`.
     ///
-    /// # Storage Accesses
+    /// # Reverts
     ///
-    /// * Reads: `1`
-    /// * Writes: `1`
+    /// * When the caller is not the contract owner.
+    /// * When `fee` is greater than `MAX_FLAT_FEE`.
     ///
-    /// # Panics
+    /// # Storage Accesses
     ///
-    /// * If the length of `recipients` and `shares` do not match.
-    #[storage(write)]
-    fn set_shares(recipients: Vec<Identity>, shares: Vec<u64>) {
+    /// * Reads: `2`
+    /// * Writes: `2`
+    #[storage(read, write)]
+    fn set_fee_for(asset: AssetId, fee: u64) {
         only_owner();
-        assert(recipients.len() == shares.len());
-        let mut total_shares = 0;
-        let mut i = 0;
-        while i < recipients.len() {
-            storage.shares.push((recipients.get(i).unwrap(), shares.get(i).unwrap_or(0)));
-            total_shares += shares.get(i).unwrap();
-            i += 1;
-        }
-        storage.total_shares.write(total_shares);
+        _change_flat_fee(asset, fee);
     }
 
-    /// Returns the share for the caller of the function.
+    /// Returns the flat fee charged per token for mints paid in the given asset.
     ///
     /// # Arguments
     ///
-    /// * `caller`: [Identity] - The identity of the caller.
+    /// * `asset`: [AssetId] - The payment asset.
     ///
     /// # Returns
     ///
-    /// * [Option<u64>] - The share of the caller, or `None` if not set.
+    /// * [Option<u64>] - The fee amount denominated in `asset`.
     ///
     /// # Storage Accesses
     ///
     /// * Reads: `1`
     #[storage(read)]
-    fn get_share() -> Option<u64> {
-        let sender = msg_sender().unwrap();
-        let mut i = 0;
-        while i < storage.shares.len() {
-            let (recipient, share) = storage.shares.get(i).unwrap().read();
-            if recipient == sender {
-                return Some(share);
-            }
-            i += 1;
-        }
-        None
+    fn fee_for(asset: AssetId) -> Option<u64> {
+        Some(_fee_config(asset).flat)
     }
 
-    /// Returns the total shares.
+    /// Sets how the fee is computed for mints paid in the given asset.
     ///
-    /// # Returns
+    /// # Additional Information
     ///
-    /// * [Option<u64>] - The total shares, or `None` if not set.
+    /// The fee is the flat amount per token plus `basis_points` of the creator price, clamped to
+    /// `min` and, when non-zero, `max`. Changes that can raise the fee of any mint are queued and only
+    /// apply once `apply_fee` is called after `FEE_CHANGE_DELAY` seconds. Other changes apply
+    /// immediately and cancel any queued increase.
     ///
-    /// # Storage Accesses
+    /// # Arguments
     ///
-    /// * Reads: `1`
-    #[storage(read)]
-    fn total_shares() -> Option<u64> {
-        Some(storage.total_shares.try_read().unwrap_or(0))
-    }
-
-    /// Distributes the received funds to recipients based on their shares.
+    /// * `asset`: [AssetId] - The payment asset.
+    /// * `config`: [FeeConfig] - The fee configuration, denominated in `asset`.
     ///
-    /// # Arguments
+    /// # Reverts
     ///
-    /// * `amount`: [u64] - The total amount of funds to be distributed.
+    /// * When the caller is not the contract owner.
+    /// * When `config.basis_points` is greater than `MAX_FEE_BASIS_POINTS`.
+    /// * When `config.flat` or `config.min` is greater than `MAX_FLAT_FEE`.
+    /// * When `config.max` is non-zero and lower than `config.min`.
     ///
     /// # Storage Accesses
     ///
-    /// * Reads: `1`
+    /// * Reads: `2`
     /// * Writes: `1`
     #[storage(read, write)]
-    fn distribute_funds(amount: u64) {
-
-        // @TODO add more checks for amounts
-
-        let total_shares = storage.total_shares.try_read().unwrap_or(1);
-        let mut i = 0;
-
-        while i < storage.shares.len() {
-            let (recipient, share) = storage.shares.get(i).unwrap().read();
-            let amount_to_send: u64 = ((amount * share) / total_shares);
-
-            require(amount_to_send > 0, DistributionError::CanNotSendZero);
-            log(amount_to_send);
-
-            transfer(recipient, AssetId::base(), amount_to_send);
-            i += 1;
-        }
+    fn set_fee_config(asset: AssetId, config: FeeConfig) {
+        only_owner();
+        _change_fee_config(asset, config);
     }
 
-    /// Receives funds and distributes them according to shares.
+    /// Returns the queued fee increase for the given asset.
     ///
-    /// # Storage Accesses
+    /// # Arguments
     ///
-    /// * Reads: `1`
+    /// * `asset`: [AssetId] - The payment asset.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<PendingFee>] - The queued configuration and when it can be applied, or `None`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn pending_fee(asset: AssetId) -> Option<PendingFee> {
+        storage.pending_fees.get(asset).try_read()
+    }
+
+    /// Applies the queued fee increase for the given asset.
+    ///
+    /// # Additional Information
+    ///
+    /// Anyone can call this function once the delay has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The payment asset.
+    ///
+    /// # Reverts
+    ///
+    /// * When no fee change is queued for `asset`.
+    /// * When the queued change is not effective yet.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    #[storage(read, write)]
+    fn apply_fee(asset: AssetId) {
+        let pending = storage.pending_fees.get(asset).try_read();
+        require(pending.is_some(), SetError::NoPendingFee);
+        let pending = pending.unwrap();
+        require(timestamp() >= pending.effective_at, SetError::FeeChangeTimelocked);
+
+        let _ = storage.pending_fees.remove(asset);
+        _set_fee_config(asset, pending.config);
+    }
+
+    /// Returns how the fee is computed for mints paid in the given asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The payment asset.
+    ///
+    /// # Returns
+    ///
+    /// * [FeeConfig] - The fee configuration, denominated in `asset`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn fee_config(asset: AssetId) -> FeeConfig {
+        _fee_config(asset)
+    }
+
+    /// Returns the fee for minting tokens paid in the base asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `price`: [u64] - The creator price of a single token.
+    /// * `amount`: [u64] - The number of tokens minted.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The fee to send along with `receive_funds`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn quote_fee(price: u64, amount: u64) -> u64 {
+        _fee_config(AssetId::base()).quote(price, amount)
+    }
+
+    /// Sets or clears a custom fee for a contract.
+    ///
+    /// # Additional Information
+    ///
+    /// The override applies to fees paid in the base asset and takes precedence over category
+    /// overrides. Use a zero configuration to waive the fee. Changes that can raise the fee the
+    /// contract pays, including removing an override or making it expire sooner, are queued and only
+    /// apply once `apply_fee_override` is called after `FEE_CHANGE_DELAY` seconds. Other changes
+    /// apply immediately and cancel any queued change.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract paying the fee.
+    /// * `fee_override`: [Option<FeeOverride>] - The custom fee, or `None` to remove it.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When the override's basis points are greater than `MAX_FEE_BASIS_POINTS`.
+    /// * When the override's flat fee or minimum is greater than `MAX_FLAT_FEE`.
+    /// * When the override's maximum is non-zero and lower than its minimum.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `5`
+    /// * Writes: `1`
+    #[storage(read, write)]
+    fn set_fee_override(contract_id: ContractId, fee_override: Option<FeeOverride>) {
+        only_owner();
+        if let Some(fee_override) = fee_override {
+            _require_valid_fee_config(fee_override.config);
+        }
+
+        let current = storage.contract_fee_overrides.get(contract_id).try_read();
+        if _is_override_raised_by(current, fee_override, _category_fee_config(contract_id)) {
+            let effective_at = timestamp() + FEE_CHANGE_DELAY;
+            storage.pending_fee_overrides.insert(contract_id, PendingFeeOverride { fee_override, effective_at });
+
+            log(FeeOverrideChangeQueuedEvent { contract_id: Some(contract_id), category: None, fee_override, effective_at });
+        } else {
+            let _ = storage.pending_fee_overrides.remove(contract_id);
+            _set_fee_override(contract_id, fee_override);
+        }
+    }
+
+    /// Returns the custom fee of a contract, including expired ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to check.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<FeeOverride>] - The custom fee, or `None` if none is set.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn fee_override(contract_id: ContractId) -> Option<FeeOverride> {
+        storage.contract_fee_overrides.get(contract_id).try_read()
+    }
+
+    /// Returns the queued override change for a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to check.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<PendingFeeOverride>] - The queued override and when it can be applied, or `None`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn pending_fee_override(contract_id: ContractId) -> Option<PendingFeeOverride> {
+        storage.pending_fee_overrides.get(contract_id).try_read()
+    }
+
+    /// Applies the queued override change for a contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Anyone can call this function once the delay has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract paying the fee.
+    ///
+    /// # Reverts
+    ///
+    /// * When no override change is queued for `contract_id`.
+    /// * When the queued change is not effective yet.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    #[storage(read, write)]
+    fn apply_fee_override(contract_id: ContractId) {
+        let pending = storage.pending_fee_overrides.get(contract_id).try_read();
+        require(pending.is_some(), SetError::NoPendingFee);
+        let pending = pending.unwrap();
+        require(timestamp() >= pending.effective_at, SetError::FeeChangeTimelocked);
+
+        let _ = storage.pending_fee_overrides.remove(contract_id);
+        _set_fee_override(contract_id, pending.fee_override);
+    }
+
+    /// Sets or clears a custom fee for every contract in a registry category.
+    ///
+    /// # Additional Information
+    ///
+    /// Category overrides only apply once a registry is set. Changes that can raise the fee of
+    /// contracts in the category are queued and only apply once `apply_category_fee_override` is
+    /// called after `FEE_CHANGE_DELAY` seconds. Other changes apply immediately and cancel any queued
+    /// change.
+    ///
+    /// # Arguments
+    ///
+    /// * `category`: [String] - The registry category.
+    /// * `fee_override`: [Option<FeeOverride>] - The custom fee, or `None` to remove it.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When the override's basis points are greater than `MAX_FEE_BASIS_POINTS`.
+    /// * When the override's flat fee or minimum is greater than `MAX_FLAT_FEE`.
+    /// * When the override's maximum is non-zero and lower than its minimum.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `3`
+    /// * Writes: `1`
+    #[storage(read, write)]
+    fn set_category_fee_override(category: String, fee_override: Option<FeeOverride>) {
+        only_owner();
+        if let Some(fee_override) = fee_override {
+            _require_valid_fee_config(fee_override.config);
+        }
+
+        let current = storage.category_fee_overrides.get(sha256(category)).try_read();
+        if _is_override_raised_by(current, fee_override, _fee_config(AssetId::base())) {
+            let effective_at = timestamp() + FEE_CHANGE_DELAY;
+            storage.pending_category_fee_overrides.insert(sha256(category), PendingFeeOverride { fee_override, effective_at });
+
+            log(FeeOverrideChangeQueuedEvent { contract_id: None, category: Some(category), fee_override, effective_at });
+        } else {
+            let _ = storage.pending_category_fee_overrides.remove(sha256(category));
+            _set_category_fee_override(category, fee_override);
+        }
+    }
+
+    /// Returns the custom fee of a registry category, including expired ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `category`: [String] - The registry category.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<FeeOverride>] - The custom fee, or `None` if none is set.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn category_fee_override(category: String) -> Option<FeeOverride> {
+        storage.category_fee_overrides.get(sha256(category)).try_read()
+    }
+
+    /// Returns the queued override change for a registry category.
+    ///
+    /// # Arguments
+    ///
+    /// * `category`: [String] - The registry category.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<PendingFeeOverride>] - The queued override and when it can be applied, or `None`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn pending_category_fee_override(category: String) -> Option<PendingFeeOverride> {
+        storage.pending_category_fee_overrides.get(sha256(category)).try_read()
+    }
+
+    /// Applies the queued override change for a registry category.
+    ///
+    /// # Additional Information
+    ///
+    /// Anyone can call this function once the delay has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `category`: [String] - The registry category.
+    ///
+    /// # Reverts
+    ///
+    /// * When no override change is queued for `category`.
+    /// * When the queued change is not effective yet.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    #[storage(read, write)]
+    fn apply_category_fee_override(category: String) {
+        let pending = storage.pending_category_fee_overrides.get(sha256(category)).try_read();
+        require(pending.is_some(), SetError::NoPendingFee);
+        let pending = pending.unwrap();
+        require(timestamp() >= pending.effective_at, SetError::FeeChangeTimelocked);
+
+        let _ = storage.pending_category_fee_overrides.remove(sha256(category));
+        _set_category_fee_override(category, pending.fee_override);
+    }
+
+    /// Queues a change of the registry used to look up the category of contracts.
+    ///
+    /// # Additional Information
+    ///
+    /// A different registry can move any contract into a category with a higher fee, so every change
+    /// is queued and only applies once `apply_registry` is called after `FEE_CHANGE_DELAY` seconds.
+    /// A new change replaces the queued one.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry`: [Option<ContractId>] - The registry, or `None` to disable category overrides.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
     /// * Writes: `1`
+    #[storage(read, write)]
+    fn set_registry(registry: Option<ContractId>) {
+        only_owner();
+        let effective_at = timestamp() + FEE_CHANGE_DELAY;
+        storage.pending_registry.write(Some(PendingRegistry { registry, effective_at }));
+
+        log(RegistryChangeQueuedEvent { registry, effective_at });
+    }
+
+    /// Returns the registry used to look up the category of contracts.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<ContractId>] - The registry, or `None` if category overrides are disabled.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn registry() -> Option<ContractId> {
+        storage.registry.try_read().unwrap_or(None)
+    }
+
+    /// Returns the queued registry change.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<PendingRegistry>] - The queued registry and when it can be applied, or `None`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn pending_registry() -> Option<PendingRegistry> {
+        storage.pending_registry.try_read().unwrap_or(None)
+    }
+
+    /// Applies the queued registry change.
+    ///
+    /// # Additional Information
+    ///
+    /// Anyone can call this function once the delay has passed.
+    ///
+    /// # Reverts
+    ///
+    /// * When no registry change is queued.
+    /// * When the queued change is not effective yet.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    #[storage(read, write)]
+    fn apply_registry() {
+        let pending = storage.pending_registry.try_read().unwrap_or(None);
+        require(pending.is_some(), SetError::NoPendingFee);
+        let pending = pending.unwrap();
+        require(timestamp() >= pending.effective_at, SetError::FeeChangeTimelocked);
+
+        storage.pending_registry.write(None);
+        storage.registry.write(pending.registry);
+
+        log(RegistrySetEvent { registry: pending.registry });
+    }
+
+    /// Returns the fee a contract pays for minting tokens in the base asset.
+    ///
+    /// # Additional Information
+    ///
+    /// NFT contracts call this with `ContractId::this()`. An active override of the contract applies
+    /// first, then an active override of its registry category, then the default configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract paying the fee.
+    /// * `price`: [u64] - The creator price of a single token.
+    /// * `amount`: [u64] - The number of tokens minted.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The fee to send along with `receive_funds`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `4`
+    #[storage(read)]
+    fn quote_fee_for(contract_id: ContractId, price: u64, amount: u64) -> u64 {
+        _resolved_fee_config(contract_id).quote(price, amount)
+    }
+
+    /// Replaces all payees and their shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipients`: [Vec<Identity>] - The new payees.
+    /// * `shares`: [Vec<u64>] - The shares of each payee, in the same order as `recipients`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When the length of `recipients` and `shares` do not match.
+    /// * When a share is zero.
+    /// * When an identity appears more than once in `recipients`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1 + 2n + a(1 + 5n)`
+    /// * Writes: `3n + 4m + a(1 + 2n)`, where `n` is the number of previous payees, `m` the number of new payees and `a` the number of received assets
+    #[storage(read, write)]
+    fn set_shares(recipients: Vec<Identity>, shares: Vec<u64>) {
+        only_owner();
+        require(recipients.len() == shares.len(), SetError::LengthMismatch);
+        _checkpoint_shares();
+
+        let mut i = 0;
+        while i < storage.payees.len() {
+            let payee = storage.payees.get(i).unwrap().read();
+            let _ = storage.shares.remove(payee);
+            let _ = storage.payee_index.remove(payee);
+
+            log(SharesSetEvent { payee, shares: 0, total_shares: 0 });
+            i += 1;
+        }
+        storage.payees.clear();
+        storage.total_shares.write(0);
+
+        let mut i = 0;
+        while i < recipients.len() {
+            _add_payee(recipients.get(i).unwrap(), shares.get(i).unwrap());
+            i += 1;
+        }
+    }
+
+    /// Adds a payee with the given shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee`: [Identity] - The identity to add.
+    /// * `share`: [u64] - The number of shares allocated to `payee`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When `share` is zero.
+    /// * When `payee` already holds shares.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `4 + a(1 + 5n)`
+    /// * Writes: `4 + a(1 + 2n)`, where `n` is the number of payees and `a` the number of received assets
+    #[storage(read, write)]
+    fn add_payee(payee: Identity, share: u64) {
+        only_owner();
+        _checkpoint_shares();
+        _add_payee(payee, share);
+    }
+
+    /// Changes the shares of an existing payee.
+    ///
+    /// # Additional Information
+    ///
+    /// Funds received before the change stay split by the previous shares.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee`: [Identity] - The payee to update.
+    /// * `share`: [u64] - The new number of shares allocated to `payee`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When `payee` is not a payee.
+    /// * When `share` is zero.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `3 + a(1 + 5n)`
+    /// * Writes: `2 + a(1 + 2n)`, where `n` is the number of payees and `a` the number of received assets
+    #[storage(read, write)]
+    fn update_share(payee: Identity, share: u64) {
+        only_owner();
+        let current = _share(payee);
+        require(current > 0, SetError::PayeeNotFound);
+        require(share > 0, SetError::ZeroShares);
+        _checkpoint_shares();
+
+        storage.shares.insert(payee, share);
+        let total_shares = storage.total_shares.read() - current + share;
+        storage.total_shares.write(total_shares);
+
+        log(SharesSetEvent { payee, shares: share, total_shares });
+    }
+
+    /// Removes a payee and its shares.
+    ///
+    /// # Additional Information
+    ///
+    /// The payee keeps its claim on the funds it earned before removal and can still `release` them.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee`: [Identity] - The payee to remove.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When `payee` is not a payee.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `5 + a(1 + 5n)`
+    /// * Writes: `5 + a(1 + 2n)`, where `n` is the number of payees and `a` the number of received assets
+    #[storage(read, write)]
+    fn remove_payee(payee: Identity) {
+        only_owner();
+        _checkpoint_shares();
+        _remove_payee(payee);
+    }
+
+    /// Returns all payees and their shares.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<(Identity, u64)>] - Each payee with its shares, in no particular order.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1 + 2n`, where `n` is the number of payees
+    #[storage(read)]
+    fn payees() -> Vec<(Identity, u64)> {
+        let mut payees = Vec::new();
+        let mut i = 0;
+        while i < storage.payees.len() {
+            let payee = storage.payees.get(i).unwrap().read();
+            payees.push((payee, _share(payee)));
+            i += 1;
+        }
+        payees
+    }
+
+    /// Returns the share for the caller of the function.
+    ///
+    /// # Arguments
+    ///
+    /// * `caller`: [Identity] - The identity of the caller.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The share of the caller, or `None` if not set.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn get_share() -> Option<u64> {
+        match _share(msg_sender().unwrap()) {
+            0 => None,
+            share => Some(share),
+        }
+    }
+
+    /// Returns the total shares.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The total shares, or `None` if not set.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn total_shares() -> Option<u64> {
+        Some(storage.total_shares.try_read().unwrap_or(0))
+    }
+
+    /// Returns the amount of an asset a payee can currently withdraw.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee`: [Identity] - The payee to check.
+    /// * `asset`: [AssetId] - The asset to check.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The amount of `asset` that `release` would transfer to `payee`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `6`
+    #[storage(read)]
+    fn releasable(payee: Identity, asset: AssetId) -> u64 {
+        _releasable(payee, asset)
+    }
+
+    /// Returns the amount of an asset already released to a payee.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee`: [Identity] - The payee to check.
+    /// * `asset`: [AssetId] - The asset to check.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The amount of `asset` released to `payee` so far.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn released(payee: Identity, asset: AssetId) -> u64 {
+        storage.released.get((payee, asset)).try_read().unwrap_or(0)
+    }
+
+    /// Transfers the funds owed to a payee.
+    ///
+    /// # Additional Information
+    ///
+    /// Anyone can call this function, the funds always go to `payee`. Payees that were removed can
+    /// still release the funds they earned before removal.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee`: [Identity] - The payee to pay out.
+    /// * `asset`: [AssetId] - The asset to pay out.
+    ///
+    /// # Reverts
+    ///
+    /// * When nothing of `asset` can be released to `payee`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `12`
+    /// * Writes: `4`
+    #[storage(read, write)]
+    fn release(payee: Identity, asset: AssetId) {
+        let amount = _releasable(payee, asset);
+        require(amount > 0, DistributionError::CanNotSendZero);
+
+        _release(payee, asset, amount);
+    }
+
+    /// Sets the undistributed balance of an asset at which `receive_funds` pays out all payees.
+    ///
+    /// # Additional Information
+    ///
+    /// Distributing in batches saves gas on small fees. Setting the threshold to zero disables
+    /// automatic distribution of `asset`. Automatic distribution is skipped while there are more than
+    /// `MAX_AUTO_DISTRIBUTION_PAYEES` payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset the threshold applies to.
+    /// * `threshold`: [u64] - The minimum balance to distribute, or zero to disable.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    #[storage(read, write)]
+    fn set_distribution_threshold(asset: AssetId, threshold: u64) {
+        only_owner();
+        storage.distribution_thresholds.insert(asset, threshold);
+
+        log(DistributionThresholdSetEvent { asset, threshold });
+    }
+
+    /// Returns the undistributed balance of an asset at which `receive_funds` pays out all payees.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to check.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<u64>] - The threshold, or `None` if automatic distribution is disabled.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `1`
+    #[storage(read)]
+    fn distribution_threshold(asset: AssetId) -> Option<u64> {
+        match storage.distribution_thresholds.get(asset).try_read().unwrap_or(0) {
+            0 => None,
+            threshold => Some(threshold),
+        }
+    }
+
+    /// Receives funds and distributes them according to shares.
+    ///
+    /// # Additional Information
+    ///
+    /// Only the base asset and assets with a fee configuration are accepted, so the set of assets
+    /// checkpointed on every share change stays bounded by the owner. Each asset is accounted for
+    /// separately. When a distribution threshold is set for the received asset and its undistributed
+    /// balance reaches it, all payees are paid out in the same call, as long as there are no more than
+    /// `MAX_AUTO_DISTRIBUTION_PAYEES`. Callers must then provide a variable output for each payee.
+    /// With more payees the funds are left for each payee to `release`.
+    ///
+    /// NFT contracts pay fees from their own balance, so the `source` of the logged event identifies
+    /// the collection the fee was earned on.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract is paused.
+    /// * When no funds are sent.
+    /// * When the asset is neither the base asset nor has a fee configuration.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `6`
+    /// * Writes: `1`, or `2` for a new asset, plus `4n` when distributing, where `n` is the number of payees
     ///
     /// # Payable
     ///
     /// This function is payable and will automatically receive funds.
-    #[storage(write), payable]
+    #[storage(read, write), payable]
     fn receive_funds() {
         require_not_paused();
-        require(msg_asset_id() == AssetId::base(), DistributionError::AssetNotBaseId);
         require(msg_amount() > 0, DistributionError::AmountIsZero);
-        // The contract automatically receives funds when this function is called.
-        let amount_received = msg_amount();
-        // distribute_funds(amount_received); //- @TODO I wish this would work need to find out how to call my self
+
+        let sender = msg_sender().unwrap();
+        let source = match sender {
+            Identity::ContractId(contract_id) => Some(contract_id),
+            Identity::Address(_) => None,
+        };
+        let asset = msg_asset_id();
+        require(
+            asset == AssetId::base() || storage.fee_configs.get(asset).try_read().is_some(),
+            DistributionError::UnsupportedAsset,
+        );
+        let amount = msg_amount();
+        let total_received = storage.total_received.get(asset).try_read().unwrap_or(0);
+        if total_received == 0 {
+            storage.received_assets.push(asset);
+        }
+        storage.total_received.insert(asset, total_received + amount);
+
+        log(FundsReceivedEvent { sender, source, asset, amount });
+
+        let threshold = storage.distribution_thresholds.get(asset).try_read().unwrap_or(0);
+        if threshold > 0 && storage.payees.len() <= MAX_AUTO_DISTRIBUTION_PAYEES && _unreleased(asset) >= threshold {
+            _distribute(asset);
+        }
+    }
+}
+
+impl Sweep for Contract {
+    /// Transfers assets that are not owed to payees to a recipient.
+    ///
+    /// # Additional Information
+    ///
+    /// Funds received through `receive_funds` belong to the payees until they are released, even when
+    /// no shares are set. Only assets transferred to the contract directly can be swept.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to transfer.
+    /// * `amount`: [u64] - The amount of `asset` to transfer.
+    /// * `recipient`: [Identity] - The identity receiving the assets.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    /// * When `amount` is zero.
+    /// * When `amount` is greater than the sweepable balance of `asset`.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `3`
+    #[storage(read)]
+    fn sweep(asset: AssetId, amount: u64, recipient: Identity) {
+        _sweep(asset, amount, recipient, _sweepable(asset));
+    }
+
+    /// Returns how much of an asset the owner can sweep.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset to check.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The balance of `asset` that is not owed to payees.
+    ///
+    /// # Storage Accesses
+    ///
+    /// * Reads: `2`
+    #[storage(read)]
+    fn sweepable(asset: AssetId) -> u64 {
+        _sweepable(asset)
     }
 }
 
@@ -0,0 +1,101 @@
+use crate::utils::{
+    interface::{constructor, add_payee, payees, set_shares, total_shares},
+    setup::{defaults, setup, SharesSetEvent},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn adds_payee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+        add_payee(&instance_1, another_identity, 100).await;
+
+        let payees = payees(&instance_1).await;
+        assert_eq!(payees.len(), 3);
+        assert!(payees.contains(&(another_identity, 100)));
+        assert_eq!(total_shares(&instance_1).await, Some(200));
+    }
+
+    #[tokio::test]
+    async fn logs_shares_set() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        add_payee(&instance_1, owner_identity.clone(), 40).await;
+        let response = add_payee(&instance_1, other_identity.clone(), 60).await;
+
+        let events = response.decode_logs_with_type::<SharesSetEvent>().unwrap();
+        assert_eq!(
+            events,
+            vec![SharesSetEvent { payee: other_identity, shares: 60, total_shares: 100 }]
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        add_payee(&instance_2, other_identity, 100).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroShares")]
+    async fn when_share_is_zero() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        add_payee(&instance_1, other_identity, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DuplicatePayee")]
+    async fn when_already_payee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        add_payee(&instance_1, other_identity, 100).await;
+        add_payee(&instance_1, other_identity, 50).await;
+    }
+}
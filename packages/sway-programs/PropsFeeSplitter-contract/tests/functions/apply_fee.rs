@@ -0,0 +1,69 @@
+use crate::utils::{
+    interface::{apply_fee, constructor, fee, pending_fee, set_fee, skip_fee_change_delay},
+    setup::{defaults, setup},
+};
+
+use fuels::types::AssetId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn applies_pending_fee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee(&instance_1, 100).await;
+
+        // Anyone can apply a fee change once it is due
+        skip_fee_change_delay(&instance_1).await;
+        let response = apply_fee(&instance_2, AssetId::zeroed()).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("FeeSetEvent")));
+
+        assert_eq!(pending_fee(&instance_1, AssetId::zeroed()).await, None);
+        assert_eq!(fee(&instance_1).await, Some(100));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NoPendingFee")]
+    async fn when_no_pending_fee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "FeeChangeTimelocked")]
+    async fn when_fee_change_is_timelocked() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee(&instance_1, 100).await;
+
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+    }
+}
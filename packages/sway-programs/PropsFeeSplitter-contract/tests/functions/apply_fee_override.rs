@@ -0,0 +1,75 @@
+use crate::utils::{
+    interface::{apply_fee_override, constructor, fee_override, pending_fee_override, set_fee_override, skip_fee_change_delay},
+    setup::{defaults, setup, FeeConfig, FeeOverride},
+};
+
+use fuels::types::ContractId;
+
+fn partner() -> ContractId {
+    ContractId::from([2u8; 32])
+}
+
+fn custom() -> FeeOverride {
+    FeeOverride { config: FeeConfig { flat: 10, basis_points: 0, min: 0, max: 0 }, expires_at: 0 }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn applies_pending_override() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee_override(&instance_1, partner(), Some(custom())).await;
+
+        // Anyone can apply an override change once it is due
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee_override(&instance_2, partner()).await;
+
+        assert_eq!(pending_fee_override(&instance_1, partner()).await, None);
+        assert_eq!(fee_override(&instance_1, partner()).await, Some(custom()));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NoPendingFee")]
+    async fn when_no_pending_override() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        apply_fee_override(&instance_1, partner()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "FeeChangeTimelocked")]
+    async fn when_override_change_is_timelocked() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee_override(&instance_1, partner(), Some(custom())).await;
+
+        apply_fee_override(&instance_1, partner()).await;
+    }
+}
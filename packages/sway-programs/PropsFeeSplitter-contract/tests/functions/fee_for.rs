@@ -0,0 +1,55 @@
+use crate::utils::{
+    interface::{apply_fee, constructor, fee, fee_for, set_fee, set_fee_for, skip_fee_change_delay},
+    setup::{defaults, other_asset, setup},
+};
+
+use fuels::types::AssetId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_fee_per_asset() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        assert_eq!(fee_for(&instance_1, other_asset()).await, Some(0));
+
+        set_fee(&instance_1, 100).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+        set_fee_for(&instance_1, other_asset(), 5).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, other_asset()).await;
+
+        assert_eq!(fee_for(&instance_1, other_asset()).await, Some(5));
+        assert_eq!(fee_for(&instance_1, AssetId::zeroed()).await, Some(100));
+        assert_eq!(fee(&instance_1).await, Some(100));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_fee_for(&instance_2, other_asset(), 5).await;
+    }
+}
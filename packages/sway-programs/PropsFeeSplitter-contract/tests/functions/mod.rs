@@ -5,4 +5,21 @@ mod set_shares;
 mod get_share;
 mod total_shares;
 mod receive_funds;
-mod distribute_funds;
\ No newline at end of file
+mod releasable;
+mod release;
+mod transfer_ownership;
+mod accept_ownership;
+mod renounce_ownership;
+mod add_payee;
+mod update_share;
+mod remove_payee;
+mod payees;
+mod set_distribution_threshold;
+mod fee_for;
+mod set_fee_config;
+mod quote_fee;
+mod set_fee_override;
+mod apply_fee;
+mod apply_fee_override;
+mod set_registry;
+mod sweep;
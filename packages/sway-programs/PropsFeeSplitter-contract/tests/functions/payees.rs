@@ -0,0 +1,42 @@
+use crate::utils::{
+    interface::{constructor, payees, set_shares},
+    setup::{defaults, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn empty_by_default() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        assert_eq!(payees(&instance_1).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn returns_all_payees() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+
+        let payees = payees(&instance_1).await;
+        assert_eq!(payees.len(), 2);
+        assert!(payees.contains(&(owner_identity, 40)));
+        assert!(payees.contains(&(other_identity, 60)));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::utils::{
+    interface::{apply_fee, constructor, quote_fee, set_fee, set_fee_config, skip_fee_change_delay},
+    setup::{defaults, setup, FeeConfig},
+};
+
+use fuels::types::AssetId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn charges_flat_fee_per_token() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee(&instance_1, 100).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        assert_eq!(quote_fee(&instance_1, 1_000, 1).await, 100);
+        assert_eq!(quote_fee(&instance_1, 1_000, 3).await, 300);
+    }
+
+    #[tokio::test]
+    async fn charges_percentage_of_price() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let config = FeeConfig { flat: 0, basis_points: 500, min: 0, max: 0 };
+        set_fee_config(&instance_1, AssetId::zeroed(), config).await;
+
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        // 5% of 2 * 1_000
+        assert_eq!(quote_fee(&instance_1, 1_000, 2).await, 100);
+    }
+
+    #[tokio::test]
+    async fn combines_and_clamps_to_bounds() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let config = FeeConfig { flat: 10, basis_points: 1_000, min: 50, max: 500 };
+        set_fee_config(&instance_1, AssetId::zeroed(), config).await;
+
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        // 10 + 10% of 100 is below the minimum
+        assert_eq!(quote_fee(&instance_1, 100, 1).await, 50);
+        // 20 + 10% of 2_000
+        assert_eq!(quote_fee(&instance_1, 1_000, 2).await, 220);
+        // 50 + 10% of 50_000 is above the maximum
+        assert_eq!(quote_fee(&instance_1, 10_000, 5).await, 500);
+    }
+
+    #[tokio::test]
+    async fn returns_zero_for_zero_amount() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let config = FeeConfig { flat: 10, basis_points: 1_000, min: 50, max: 500 };
+        set_fee_config(&instance_1, AssetId::zeroed(), config).await;
+
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        assert_eq!(quote_fee(&instance_1, 1_000, 0).await, 0);
+    }
+
+    #[tokio::test]
+    async fn does_not_overflow_on_large_prices() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let config = FeeConfig { flat: 0, basis_points: 1_000, min: 0, max: 0 };
+        set_fee_config(&instance_1, AssetId::zeroed(), config).await;
+
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        // price * amount * basis_points does not fit in a u64, but 10% of price * amount does
+        assert_eq!(quote_fee(&instance_1, 1_000_000_000_000_000_000, 100).await, 10_000_000_000_000_000_000);
+    }
+}
@@ -1,10 +1,12 @@
 use crate::utils::{
-    interface::{constructor, receive_funds},
-    setup::{defaults, setup},
+    interface::{
+        constructor, receive_asset, receive_funds, releasable, set_distribution_threshold, set_fee_for, set_shares,
+    },
+    setup::{defaults, other_asset, setup, FundsReceivedEvent},
 };
 
 use fuels::{
-    types::{AssetId},
+    types::{Address, AssetId, Identity},
 };
 
 mod success {
@@ -34,25 +36,123 @@ mod success {
         println!("base_asset_balance: {:?}", base_asset_balance);
         assert_eq!(base_asset_balance, amount);
     }
+
+    #[tokio::test]
+    async fn accepts_other_assets() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee_for(&instance_1, other_asset(), 0).await;
+
+        receive_asset(&instance_1, 500, other_asset()).await;
+
+        let contract_balances = instance_1.get_balances().await.unwrap();
+        assert_eq!(contract_balances.get(&other_asset()).copied().unwrap_or(0), 500);
+    }
+
+    #[tokio::test]
+    async fn logs_funds_received() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone()).await;
+        set_fee_for(&instance_1, other_asset(), 0).await;
+
+        let response = receive_asset(&instance_1, 500, other_asset()).await;
+
+        // Funds sent by a wallet have no source contract
+        let events = response.decode_logs_with_type::<FundsReceivedEvent>().unwrap();
+        assert_eq!(
+            events,
+            vec![FundsReceivedEvent { sender: owner_identity, source: None, asset: other_asset(), amount: 500 }]
+        );
+    }
+
+    #[tokio::test]
+    async fn distributes_once_threshold_is_reached() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(&instance_1, vec![other_identity.clone(), another_identity.clone()], vec![1, 2]).await;
+        set_distribution_threshold(&instance_1, AssetId::zeroed(), 100).await;
+
+        let initial_other_balance = other_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap();
+        let initial_another_balance = another_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap();
+
+        // Below the threshold the funds stay in the contract
+        receive_funds(&instance_1, 50).await;
+        assert_eq!(other_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap(), initial_other_balance);
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 16);
+
+        // Reaching the threshold pays out everything received so far, keeping the dust
+        receive_funds(&instance_1, 50).await;
+        assert_eq!(other_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap(), initial_other_balance + 33);
+        assert_eq!(another_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap(), initial_another_balance + 66);
+
+        let contract_balances = instance_1.get_balances().await.unwrap();
+        assert_eq!(contract_balances.get(&AssetId::zeroed()).copied().unwrap_or(0), 1);
+
+        // The dust is included in the next distribution
+        receive_funds(&instance_1, 200).await;
+        assert_eq!(other_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap(), initial_other_balance + 100);
+        assert_eq!(another_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap(), initial_another_balance + 200);
+    }
+
+    #[tokio::test]
+    async fn leaves_funds_for_release_with_many_payees() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        // One more payee than `receive_funds` pays out automatically
+        let payees: Vec<Identity> = (1..=11u8).map(|i| Identity::Address(Address::from([i; 32]))).collect();
+        set_shares(&instance_1, payees.clone(), vec![1; 11]).await;
+        set_distribution_threshold(&instance_1, AssetId::zeroed(), 100).await;
+
+        receive_funds(&instance_1, 1_100).await;
+
+        let contract_balances = instance_1.get_balances().await.unwrap();
+        assert_eq!(contract_balances.get(&AssetId::zeroed()).copied().unwrap_or(0), 1_100);
+        assert_eq!(releasable(&instance_1, payees[0].clone(), AssetId::zeroed()).await, 100);
+        assert_eq!(releasable(&instance_1, payees[10].clone(), AssetId::zeroed()).await, 100);
+    }
 }
 
-// mod revert {
+mod revert {
 
-//     use super::*;
+    use super::*;
 
-//     #[tokio::test]
-//     #[should_panic(expected = "NotOwner")]
-//     async fn when_not_owner() {
-//         let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
-//         let (
-//             owner_identity,
-//             other_identity,
-//             another_identity,
-//         ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+    #[tokio::test]
+    #[should_panic(expected = "UnsupportedAsset")]
+    async fn when_asset_has_no_fee_config() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
 
-//         constructor(&instance_1, owner_identity).await;
+        constructor(&instance_1, owner_identity).await;
 
-//         // Attempt to set shares with another_wallet which is not the owner
-//         set_shares(&instance_2, vec![another_identity.clone()], vec![100]).await;
-//     }
-// }
+        receive_asset(&instance_1, 500, other_asset()).await;
+    }
+}
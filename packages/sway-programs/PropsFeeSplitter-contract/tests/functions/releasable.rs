@@ -0,0 +1,52 @@
+use crate::utils::{
+    interface::{constructor, receive_funds, releasable, set_shares},
+    setup::{defaults, setup},
+};
+
+use fuels::types::AssetId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn zero_without_shares() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        receive_funds(&instance_1, 1_000).await;
+
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 0);
+    }
+
+    #[tokio::test]
+    async fn rounds_down_and_keeps_dust() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(
+            &instance_1,
+            vec![owner_identity.clone(), other_identity.clone(), another_identity.clone()],
+            vec![1, 1, 1],
+        ).await;
+        receive_funds(&instance_1, 100).await;
+
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 33);
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 33);
+        assert_eq!(releasable(&instance_1, another_identity, AssetId::zeroed()).await, 33);
+
+        receive_funds(&instance_1, 2).await;
+
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 34);
+    }
+}
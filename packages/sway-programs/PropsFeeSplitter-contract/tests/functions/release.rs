@@ -0,0 +1,185 @@
+use crate::utils::{
+    interface::{
+        add_payee, constructor, receive_asset, receive_funds, release, releasable, released, set_fee_for, set_shares,
+    },
+    setup::{defaults, other_asset, setup, FundsReleasedEvent},
+};
+
+use fuels::{
+    prelude::*,
+    types::{AssetId},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn releases_pro_rata_share() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+
+        let amount = 1_000;
+        receive_funds(&instance_1, amount).await;
+
+        let initial_other_balance = other_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap();
+
+        // Anyone can trigger the release, the funds always go to the payee
+        release(&instance_1, other_identity, AssetId::zeroed()).await;
+
+        let other_balance = other_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap();
+        assert_eq!(other_balance, initial_other_balance + 600);
+        assert_eq!(released(&instance_1, other_identity, AssetId::zeroed()).await, 600);
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 0);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 400);
+    }
+
+    #[tokio::test]
+    async fn logs_funds_released() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+        receive_funds(&instance_1, 1_000).await;
+
+        let response = release(&instance_1, other_identity.clone(), AssetId::zeroed()).await;
+
+        let events = response.decode_logs_with_type::<FundsReleasedEvent>().unwrap();
+        assert_eq!(
+            events,
+            vec![FundsReleasedEvent { payee: other_identity, asset: AssetId::zeroed(), amount: 600 }]
+        );
+    }
+
+    #[tokio::test]
+    async fn accounts_for_funds_received_after_release() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![50, 50]).await;
+
+        receive_funds(&instance_1, 1_000).await;
+        release(&instance_1, other_identity, AssetId::zeroed()).await;
+        receive_funds(&instance_1, 1_000).await;
+
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 500);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 1_000);
+
+        release(&instance_1, other_identity, AssetId::zeroed()).await;
+        release(&instance_1, owner_identity, AssetId::zeroed()).await;
+
+        let contract_balances = instance_1.get_balances().await.unwrap();
+        assert_eq!(contract_balances.get(&AssetId::zeroed()).copied().unwrap_or(0), 0);
+    }
+
+    #[tokio::test]
+    async fn releases_each_asset_separately() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+
+        set_fee_for(&instance_1, other_asset(), 0).await;
+        receive_funds(&instance_1, 1_000).await;
+        receive_asset(&instance_1, 500, other_asset()).await;
+
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 600);
+        assert_eq!(releasable(&instance_1, other_identity, other_asset()).await, 300);
+
+        let initial_other_balance = other_wallet.get_asset_balance(&other_asset()).await.unwrap();
+
+        release(&instance_1, other_identity, other_asset()).await;
+
+        let other_balance = other_wallet.get_asset_balance(&other_asset()).await.unwrap();
+        assert_eq!(other_balance, initial_other_balance + 300);
+        assert_eq!(released(&instance_1, other_identity, other_asset()).await, 300);
+        assert_eq!(released(&instance_1, other_identity, AssetId::zeroed()).await, 0);
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 600);
+    }
+
+    #[tokio::test]
+    async fn late_payee_only_shares_in_funds_received_after_joining() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(&instance_1, vec![owner_identity.clone()], vec![50]).await;
+        receive_funds(&instance_1, 1_000).await;
+
+        add_payee(&instance_1, other_identity, 50).await;
+
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 0);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 1_000);
+
+        receive_funds(&instance_1, 1_000).await;
+
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 500);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 1_500);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "CanNotSendZero")]
+    async fn when_not_payee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+        receive_funds(&instance_1, 1_000).await;
+
+        release(&instance_1, another_identity, AssetId::zeroed()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CanNotSendZero")]
+    async fn when_already_released() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+        receive_funds(&instance_1, 1_000).await;
+        release(&instance_1, other_identity, AssetId::zeroed()).await;
+
+        release(&instance_1, other_identity, AssetId::zeroed()).await;
+    }
+}
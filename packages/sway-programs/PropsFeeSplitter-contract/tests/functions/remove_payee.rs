@@ -0,0 +1,110 @@
+use crate::utils::{
+    interface::{constructor, get_share, payees, receive_funds, releasable, release, remove_payee, set_shares, total_shares},
+    setup::{defaults, setup},
+};
+
+use fuels::{
+    prelude::*,
+    types::AssetId,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn removes_payee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(
+            &instance_1,
+            vec![owner_identity.clone(), other_identity.clone(), another_identity.clone()],
+            vec![20, 30, 50],
+        ).await;
+        remove_payee(&instance_1, other_identity).await;
+
+        assert_eq!(get_share(&instance_2).await, None);
+        assert_eq!(total_shares(&instance_1).await, Some(70));
+        let payees = payees(&instance_1).await;
+        assert_eq!(payees.len(), 2);
+        assert!(payees.contains(&(owner_identity, 20)));
+        assert!(payees.contains(&(another_identity, 50)));
+    }
+
+    #[tokio::test]
+    async fn keeps_funds_received_before_removal() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+        receive_funds(&instance_1, 1_000).await;
+
+        remove_payee(&instance_1, other_identity).await;
+
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 600);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 400);
+
+        receive_funds(&instance_1, 1_000).await;
+
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 600);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 1_400);
+
+        // A removed payee can still release what it earned
+        let initial_other_balance = other_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap();
+        release(&instance_1, other_identity, AssetId::zeroed()).await;
+
+        let other_balance = other_wallet.get_asset_balance(&AssetId::zeroed()).await.unwrap();
+        assert_eq!(other_balance, initial_other_balance + 600);
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 0);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![other_identity.clone()], vec![60]).await;
+
+        remove_payee(&instance_2, other_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "PayeeNotFound")]
+    async fn when_not_payee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        remove_payee(&instance_1, other_identity).await;
+    }
+}
@@ -0,0 +1,52 @@
+use crate::utils::{
+    interface::{constructor, distribution_threshold, set_distribution_threshold},
+    setup::{defaults, setup},
+};
+
+use fuels::types::AssetId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_and_disables_threshold() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        assert_eq!(distribution_threshold(&instance_1, AssetId::zeroed()).await, None);
+
+        let response = set_distribution_threshold(&instance_1, AssetId::zeroed(), 1_000).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("DistributionThresholdSetEvent")));
+        assert_eq!(distribution_threshold(&instance_1, AssetId::zeroed()).await, Some(1_000));
+
+        set_distribution_threshold(&instance_1, AssetId::zeroed(), 0).await;
+        assert_eq!(distribution_threshold(&instance_1, AssetId::zeroed()).await, None);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_distribution_threshold(&instance_2, AssetId::zeroed(), 1_000).await;
+    }
+}
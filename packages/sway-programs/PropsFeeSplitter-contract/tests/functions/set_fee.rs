@@ -1,8 +1,10 @@
 use crate::utils::{
-    interface::{constructor, set_fee, fee},
+    interface::{apply_fee, constructor, set_fee, fee, pending_fee, skip_fee_change_delay},
     setup::{defaults, setup},
 };
 
+use fuels::types::AssetId;
+
 mod success {
 
     use super::*;
@@ -21,9 +23,54 @@ mod success {
         assert_eq!(fee(&instance_1).await, Some(0));
 
         set_fee(&instance_1, 100).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
 
         assert_eq!(fee(&instance_1).await, Some(100));
     }
+
+    #[tokio::test]
+    async fn queues_fee_increase() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let response = set_fee(&instance_1, 100).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("FeeChangeQueuedEvent")));
+
+        let pending = pending_fee(&instance_1, AssetId::zeroed()).await.unwrap();
+        assert_eq!(pending.config.flat, 100);
+        assert_eq!(fee(&instance_1).await, Some(0));
+    }
+
+    #[tokio::test]
+    async fn lowers_fee_immediately() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_fee(&instance_1, 100).await;
+        assert!(pending_fee(&instance_1, AssetId::zeroed()).await.is_some());
+
+        // Lowering the fee cancels the queued increase
+        let response = set_fee(&instance_1, 0).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("FeeSetEvent")));
+
+        assert_eq!(pending_fee(&instance_1, AssetId::zeroed()).await, None);
+        assert_eq!(fee(&instance_1).await, Some(0));
+    }
 }
 
 mod revert {
@@ -45,4 +92,19 @@ mod revert {
         // Attempt to set fee with another identity (not the owner)
         set_fee(&instance_2, 100).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "FeeExceedsMaximum")]
+    async fn when_fee_exceeds_maximum() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_fee(&instance_1, 1_000_001).await;
+    }
 }
@@ -0,0 +1,84 @@
+use crate::utils::{
+    interface::{apply_fee, constructor, fee, fee_config, set_fee_config, skip_fee_change_delay},
+    setup::{defaults, setup, FeeConfig},
+};
+
+use fuels::types::AssetId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_fee_config() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let config = FeeConfig { flat: 10, basis_points: 250, min: 50, max: 1_000 };
+        set_fee_config(&instance_1, AssetId::zeroed(), config.clone()).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        assert_eq!(fee_config(&instance_1, AssetId::zeroed()).await, config);
+        assert_eq!(fee(&instance_1).await, Some(10));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let config = FeeConfig { flat: 10, basis_points: 0, min: 0, max: 0 };
+        set_fee_config(&instance_2, AssetId::zeroed(), config).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidBasisPoints")]
+    async fn when_basis_points_exceed_100_percent() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let config = FeeConfig { flat: 0, basis_points: 10_001, min: 0, max: 0 };
+        set_fee_config(&instance_1, AssetId::zeroed(), config).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidFeeBounds")]
+    async fn when_max_is_below_min() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let config = FeeConfig { flat: 0, basis_points: 100, min: 100, max: 50 };
+        set_fee_config(&instance_1, AssetId::zeroed(), config).await;
+    }
+}
@@ -0,0 +1,189 @@
+use crate::utils::{
+    interface::{
+        apply_fee, apply_fee_override, constructor, fee_override, pending_fee_override, quote_fee_for, set_fee,
+        set_fee_override, skip_fee_change_delay,
+    },
+    setup::{defaults, setup, FeeConfig, FeeOverride},
+};
+
+use fuels::types::{AssetId, ContractId};
+
+fn partner() -> ContractId {
+    ContractId::from([2u8; 32])
+}
+
+fn zero_fee() -> FeeConfig {
+    FeeConfig { flat: 0, basis_points: 0, min: 0, max: 0 }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn applies_override_to_contract_only() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee(&instance_1, 100).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        let config = FeeConfig { flat: 10, basis_points: 0, min: 0, max: 0 };
+        let custom = FeeOverride { config, expires_at: 0 };
+        let response = set_fee_override(&instance_1, partner(), Some(custom.clone())).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("FeeOverrideSetEvent")));
+
+        assert_eq!(fee_override(&instance_1, partner()).await, Some(custom));
+        assert_eq!(quote_fee_for(&instance_1, partner(), 1_000, 2).await, 20);
+        assert_eq!(quote_fee_for(&instance_1, ContractId::from([3u8; 32]), 1_000, 2).await, 200);
+    }
+
+    #[tokio::test]
+    async fn waives_fee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee(&instance_1, 100).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        set_fee_override(&instance_1, partner(), Some(FeeOverride { config: zero_fee(), expires_at: 0 })).await;
+
+        assert_eq!(quote_fee_for(&instance_1, partner(), 1_000, 1).await, 0);
+    }
+
+    #[tokio::test]
+    async fn ignores_expired_override() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee(&instance_1, 100).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+
+        set_fee_override(&instance_1, partner(), Some(FeeOverride { config: zero_fee(), expires_at: 1 })).await;
+
+        assert_eq!(quote_fee_for(&instance_1, partner(), 1_000, 1).await, 100);
+    }
+
+    #[tokio::test]
+    async fn removes_override() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee(&instance_1, 100).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+        set_fee_override(&instance_1, partner(), Some(FeeOverride { config: zero_fee(), expires_at: 0 })).await;
+
+        // Removing a waiver raises the fee, so it waits for the timelock
+        set_fee_override(&instance_1, partner(), None).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee_override(&instance_1, partner()).await;
+
+        assert_eq!(fee_override(&instance_1, partner()).await, None);
+        assert_eq!(quote_fee_for(&instance_1, partner(), 1_000, 1).await, 100);
+    }
+
+    #[tokio::test]
+    async fn queues_override_that_raises_fee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let config = FeeConfig { flat: 10, basis_points: 0, min: 0, max: 0 };
+        let custom = FeeOverride { config, expires_at: 0 };
+        let response = set_fee_override(&instance_1, partner(), Some(custom.clone())).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("FeeOverrideChangeQueuedEvent")));
+
+        assert_eq!(fee_override(&instance_1, partner()).await, None);
+        assert_eq!(pending_fee_override(&instance_1, partner()).await.unwrap().fee_override, Some(custom));
+        assert_eq!(quote_fee_for(&instance_1, partner(), 1_000, 2).await, 0);
+    }
+
+    #[tokio::test]
+    async fn queues_waiver_that_expires_sooner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_fee(&instance_1, 100).await;
+        skip_fee_change_delay(&instance_1).await;
+        apply_fee(&instance_1, AssetId::zeroed()).await;
+        set_fee_override(&instance_1, partner(), Some(FeeOverride { config: zero_fee(), expires_at: 0 })).await;
+
+        let expiring = FeeOverride { config: zero_fee(), expires_at: u64::MAX };
+        set_fee_override(&instance_1, partner(), Some(expiring.clone())).await;
+
+        assert_eq!(fee_override(&instance_1, partner()).await, Some(FeeOverride { config: zero_fee(), expires_at: 0 }));
+        assert_eq!(pending_fee_override(&instance_1, partner()).await.unwrap().fee_override, Some(expiring));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_fee_override(&instance_2, partner(), Some(FeeOverride { config: zero_fee(), expires_at: 0 })).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidFeeBounds")]
+    async fn when_bounds_are_invalid() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let config = FeeConfig { flat: 0, basis_points: 100, min: 100, max: 50 };
+        set_fee_override(&instance_1, partner(), Some(FeeOverride { config, expires_at: 0 })).await;
+    }
+}
@@ -0,0 +1,91 @@
+use crate::utils::{
+    interface::{apply_registry, constructor, pending_registry, registry, set_registry, skip_fee_change_delay},
+    setup::{defaults, setup},
+};
+
+use fuels::types::ContractId;
+
+fn new_registry() -> ContractId {
+    ContractId::from([4u8; 32])
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn queues_registry_change() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let response = set_registry(&instance_1, Some(new_registry())).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("RegistryChangeQueuedEvent")));
+
+        assert_eq!(registry(&instance_1).await, None);
+        assert_eq!(pending_registry(&instance_1).await.unwrap().registry, Some(new_registry()));
+    }
+
+    #[tokio::test]
+    async fn applies_registry_change() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_registry(&instance_1, Some(new_registry())).await;
+
+        skip_fee_change_delay(&instance_1).await;
+        let response = apply_registry(&instance_2).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("RegistrySetEvent")));
+
+        assert_eq!(registry(&instance_1).await, Some(new_registry()));
+        assert_eq!(pending_registry(&instance_1).await, None);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_registry(&instance_2, Some(new_registry())).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "FeeChangeTimelocked")]
+    async fn when_registry_change_is_timelocked() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        set_registry(&instance_1, Some(new_registry())).await;
+
+        apply_registry(&instance_1).await;
+    }
+}
@@ -1,5 +1,5 @@
 use crate::utils::{
-    interface::{constructor, set_shares, get_share},
+    interface::{constructor, set_shares, get_share, payees, total_shares},
     setup::{defaults, setup},
 };
 
@@ -29,6 +29,26 @@ mod success {
         let other_share = get_share(&instance_2).await;
         assert_eq!(other_share, Some(100));
     }
+
+    #[tokio::test]
+    async fn replaces_previous_shares() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![50, 100]).await;
+        set_shares(&instance_1, vec![owner_identity.clone(), another_identity.clone()], vec![30, 70]).await;
+
+        assert_eq!(get_share(&instance_1).await, Some(30));
+        assert_eq!(get_share(&instance_2).await, None);
+        assert_eq!(total_shares(&instance_1).await, Some(100));
+        assert_eq!(payees(&instance_1).await.len(), 2);
+    }
 }
 
 mod revert {
@@ -50,4 +70,49 @@ mod revert {
         // Attempt to set shares with another_wallet which is not the owner
         set_shares(&instance_2, vec![another_identity.clone()], vec![100]).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "LengthMismatch")]
+    async fn when_lengths_do_not_match() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![100]).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroShares")]
+    async fn when_share_is_zero() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![100, 0]).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DuplicatePayee")]
+    async fn when_payee_is_duplicated() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), owner_identity.clone()], vec![50, 50]).await;
+    }
 }
@@ -0,0 +1,117 @@
+use crate::utils::{
+    interface::{constructor, receive_funds, releasable, set_shares, sweep, sweepable, transfer_to_contract},
+    setup::{defaults, other_asset, setup, FundsSweptEvent},
+};
+
+use fuels::{
+    prelude::*,
+    types::{AssetId},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sweeps_assets_sent_by_mistake() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone()).await;
+        transfer_to_contract(&owner_wallet, &instance_1, 500, other_asset()).await;
+        assert_eq!(sweepable(&instance_1, other_asset()).await, 500);
+
+        let initial_other_balance = other_wallet.get_asset_balance(&other_asset()).await.unwrap();
+
+        let response = sweep(&instance_1, other_asset(), 200, other_identity.clone()).await;
+
+        let events = response.decode_logs_with_type::<FundsSweptEvent>().unwrap();
+        assert_eq!(
+            events,
+            vec![FundsSweptEvent { asset: other_asset(), amount: 200, recipient: other_identity, sender: owner_identity }]
+        );
+        assert_eq!(other_wallet.get_asset_balance(&other_asset()).await.unwrap(), initial_other_balance + 200);
+        assert_eq!(sweepable(&instance_1, other_asset()).await, 300);
+    }
+
+    #[tokio::test]
+    async fn leaves_funds_owed_to_payees() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone()).await;
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+
+        receive_funds(&instance_1, 1_000).await;
+        transfer_to_contract(&owner_wallet, &instance_1, 300, AssetId::zeroed()).await;
+        assert_eq!(sweepable(&instance_1, AssetId::zeroed()).await, 300);
+
+        sweep(&instance_1, AssetId::zeroed(), 300, owner_identity.clone()).await;
+
+        assert_eq!(sweepable(&instance_1, AssetId::zeroed()).await, 0);
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 600);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 400);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        transfer_to_contract(&owner_wallet, &instance_1, 500, other_asset()).await;
+
+        sweep(&instance_2, other_asset(), 500, other_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AmountIsZero")]
+    async fn when_amount_is_zero() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone()).await;
+
+        sweep(&instance_1, other_asset(), 0, owner_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ExceedsSweepableBalance")]
+    async fn when_funds_are_owed_to_payees() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity.clone()).await;
+
+        // Funds received without any shares set are still kept for future payees
+        receive_funds(&instance_1, 1_000).await;
+
+        sweep(&instance_1, AssetId::zeroed(), 1, owner_identity).await;
+    }
+}
@@ -0,0 +1,109 @@
+use crate::utils::{
+    interface::{constructor, get_share, receive_funds, releasable, set_shares, total_shares, update_share},
+    setup::{defaults, setup},
+};
+
+use fuels::types::AssetId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn updates_share() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+        update_share(&instance_1, other_identity, 10).await;
+
+        assert_eq!(get_share(&instance_2).await, Some(10));
+        assert_eq!(total_shares(&instance_1).await, Some(50));
+    }
+
+    #[tokio::test]
+    async fn keeps_funds_received_before_reduction() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![owner_identity.clone(), other_identity.clone()], vec![40, 60]).await;
+        receive_funds(&instance_1, 1_000).await;
+
+        update_share(&instance_1, other_identity, 10).await;
+
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 600);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 400);
+
+        // Only funds received after the change are split 40/10
+        receive_funds(&instance_1, 1_000).await;
+
+        assert_eq!(releasable(&instance_1, other_identity, AssetId::zeroed()).await, 800);
+        assert_eq!(releasable(&instance_1, owner_identity, AssetId::zeroed()).await, 1_200);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![other_identity.clone()], vec![60]).await;
+
+        update_share(&instance_2, other_identity, 100).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "PayeeNotFound")]
+    async fn when_not_payee() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        update_share(&instance_1, other_identity, 100).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroShares")]
+    async fn when_share_is_zero() {
+        let (owner_wallet, other_wallet, another_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+            _another_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone(), another_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_shares(&instance_1, vec![other_identity.clone()], vec![60]).await;
+
+        update_share(&instance_1, other_identity, 0).await;
+    }
+}
@@ -1,6 +1,8 @@
-use crate::utils::setup::{State, PropsFeeSplitter};
+use crate::utils::setup::{FeeConfig, FeeOverride, PendingFee, PendingFeeOverride, PendingRegistry, State, PropsFeeSplitter};
+use chrono::{Duration, Utc};
 use fuels::{
-    prelude::{AssetId, CallParameters, WalletUnlocked},
+    accounts::{Account, ViewOnlyAccount},
+    prelude::{AssetId, CallParameters, ContractId, TxPolicies, WalletUnlocked},
     programs::{call_response::FuelCallResponse, call_utils::TxDependencyExtension},
     types::{Identity},
 };
@@ -28,6 +30,25 @@ pub(crate) async fn set_fee(
         .unwrap()
 }
 
+pub(crate) async fn pending_fee(contract: &PropsFeeSplitter<WalletUnlocked>, asset: AssetId) -> Option<PendingFee> {
+    contract.methods().pending_fee(asset).call().await.unwrap().value
+}
+
+pub(crate) async fn apply_fee(contract: &PropsFeeSplitter<WalletUnlocked>, asset: AssetId) -> FuelCallResponse<()> {
+    contract.methods().apply_fee(asset).call().await.unwrap()
+}
+
+/// Moves the chain past the fee change delay.
+pub(crate) async fn skip_fee_change_delay(contract: &PropsFeeSplitter<WalletUnlocked>) {
+    let provider = contract.account().try_provider().unwrap().clone();
+    // Relative to the chain rather than the wall clock, so the delay can be skipped more than once
+    let now = provider.latest_block_time().await.unwrap().unwrap_or_else(Utc::now);
+    provider
+        .produce_blocks(1, Some(now + Duration::days(8)))
+        .await
+        .unwrap();
+}
+
 pub(crate) async fn fee(contract: &PropsFeeSplitter<WalletUnlocked>) -> Option<u64> {
     contract
         .methods()
@@ -38,6 +59,88 @@ pub(crate) async fn fee(contract: &PropsFeeSplitter<WalletUnlocked>) -> Option<u
         .value
 }
 
+pub(crate) async fn set_fee_for(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    asset: AssetId,
+    fee: u64,
+) -> FuelCallResponse<()> {
+    contract.methods().set_fee_for(asset, fee).call().await.unwrap()
+}
+
+pub(crate) async fn fee_for(contract: &PropsFeeSplitter<WalletUnlocked>, asset: AssetId) -> Option<u64> {
+    contract.methods().fee_for(asset).call().await.unwrap().value
+}
+
+pub(crate) async fn set_fee_config(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    asset: AssetId,
+    config: FeeConfig,
+) -> FuelCallResponse<()> {
+    contract.methods().set_fee_config(asset, config).call().await.unwrap()
+}
+
+pub(crate) async fn fee_config(contract: &PropsFeeSplitter<WalletUnlocked>, asset: AssetId) -> FeeConfig {
+    contract.methods().fee_config(asset).call().await.unwrap().value
+}
+
+pub(crate) async fn quote_fee(contract: &PropsFeeSplitter<WalletUnlocked>, price: u64, amount: u64) -> u64 {
+    contract.methods().quote_fee(price, amount).call().await.unwrap().value
+}
+
+pub(crate) async fn set_fee_override(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    contract_id: ContractId,
+    fee_override: Option<FeeOverride>,
+) -> FuelCallResponse<()> {
+    contract.methods().set_fee_override(contract_id, fee_override).call().await.unwrap()
+}
+
+pub(crate) async fn fee_override(contract: &PropsFeeSplitter<WalletUnlocked>, contract_id: ContractId) -> Option<FeeOverride> {
+    contract.methods().fee_override(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn pending_fee_override(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    contract_id: ContractId,
+) -> Option<PendingFeeOverride> {
+    contract.methods().pending_fee_override(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn apply_fee_override(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    contract_id: ContractId,
+) -> FuelCallResponse<()> {
+    contract.methods().apply_fee_override(contract_id).call().await.unwrap()
+}
+
+pub(crate) async fn set_registry(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    registry: Option<ContractId>,
+) -> FuelCallResponse<()> {
+    contract.methods().set_registry(registry).call().await.unwrap()
+}
+
+pub(crate) async fn registry(contract: &PropsFeeSplitter<WalletUnlocked>) -> Option<ContractId> {
+    contract.methods().registry().call().await.unwrap().value
+}
+
+pub(crate) async fn pending_registry(contract: &PropsFeeSplitter<WalletUnlocked>) -> Option<PendingRegistry> {
+    contract.methods().pending_registry().call().await.unwrap().value
+}
+
+pub(crate) async fn apply_registry(contract: &PropsFeeSplitter<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().apply_registry().call().await.unwrap()
+}
+
+pub(crate) async fn quote_fee_for(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    contract_id: ContractId,
+    price: u64,
+    amount: u64,
+) -> u64 {
+    contract.methods().quote_fee_for(contract_id, price, amount).call().await.unwrap().value
+}
+
 pub(crate) async fn set_shares(
     contract: &PropsFeeSplitter<WalletUnlocked>,
     recipients: Vec<Identity>,
@@ -51,6 +154,33 @@ pub(crate) async fn set_shares(
         .unwrap()
 }
 
+pub(crate) async fn add_payee(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    payee: Identity,
+    share: u64,
+) -> FuelCallResponse<()> {
+    contract.methods().add_payee(payee, share).call().await.unwrap()
+}
+
+pub(crate) async fn update_share(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    payee: Identity,
+    share: u64,
+) -> FuelCallResponse<()> {
+    contract.methods().update_share(payee, share).call().await.unwrap()
+}
+
+pub(crate) async fn remove_payee(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    payee: Identity,
+) -> FuelCallResponse<()> {
+    contract.methods().remove_payee(payee).call().await.unwrap()
+}
+
+pub(crate) async fn payees(contract: &PropsFeeSplitter<WalletUnlocked>) -> Vec<(Identity, u64)> {
+    contract.methods().payees().call().await.unwrap().value
+}
+
 pub(crate) async fn get_share(
     contract: &PropsFeeSplitter<WalletUnlocked>,
 ) -> Option<u64> {
@@ -78,27 +208,107 @@ pub(crate) async fn total_shares(
 pub(crate) async fn receive_funds(
     contract: &PropsFeeSplitter<WalletUnlocked>,
     amount: u64,
+) -> FuelCallResponse<()> {
+    receive_asset(contract, amount, AssetId::zeroed()).await
+}
+
+pub(crate) async fn receive_asset(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    amount: u64,
+    asset: AssetId,
 ) -> FuelCallResponse<()> {
     contract
         .methods()
         .receive_funds()
-        .call_params(CallParameters::new(amount, AssetId::zeroed(), 1_000_000))
+        .append_variable_outputs(3)
+        .call_params(CallParameters::new(amount, asset, 1_000_000))
         .unwrap()
         .call()
         .await
         .unwrap()
 }
 
-pub(crate) async fn distribute_funds(
+pub(crate) async fn releasable(contract: &PropsFeeSplitter<WalletUnlocked>, payee: Identity, asset: AssetId) -> u64 {
+    contract.methods().releasable(payee, asset).call().await.unwrap().value
+}
+
+pub(crate) async fn released(contract: &PropsFeeSplitter<WalletUnlocked>, payee: Identity, asset: AssetId) -> u64 {
+    contract.methods().released(payee, asset).call().await.unwrap().value
+}
+
+pub(crate) async fn release(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    payee: Identity,
+    asset: AssetId,
+) -> FuelCallResponse<()> {
+    contract
+        .methods()
+        .release(payee, asset)
+        .append_variable_outputs(1)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn set_distribution_threshold(
     contract: &PropsFeeSplitter<WalletUnlocked>,
+    asset: AssetId,
+    threshold: u64,
+) -> FuelCallResponse<()> {
+    contract.methods().set_distribution_threshold(asset, threshold).call().await.unwrap()
+}
+
+pub(crate) async fn distribution_threshold(contract: &PropsFeeSplitter<WalletUnlocked>, asset: AssetId) -> Option<u64> {
+    contract.methods().distribution_threshold(asset).call().await.unwrap().value
+}
+
+pub(crate) async fn sweep(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    asset: AssetId,
     amount: u64,
-    _recipient_count: u64,
+    recipient: Identity,
 ) -> FuelCallResponse<()> {
     contract
         .methods()
-        .distribute_funds(amount)
-        .append_variable_outputs(2)
+        .sweep(asset, amount, recipient)
+        .append_variable_outputs(1)
         .call()
         .await
         .unwrap()
 }
+
+pub(crate) async fn sweepable(contract: &PropsFeeSplitter<WalletUnlocked>, asset: AssetId) -> u64 {
+    contract.methods().sweepable(asset).call().await.unwrap().value
+}
+
+// Sends assets to the contract without calling `receive_funds`
+pub(crate) async fn transfer_to_contract(
+    wallet: &WalletUnlocked,
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    amount: u64,
+    asset: AssetId,
+) {
+    wallet
+        .force_transfer_to_contract(contract.contract_id(), amount, asset, TxPolicies::default())
+        .await
+        .unwrap();
+}
+
+pub(crate) async fn transfer_ownership(
+    contract: &PropsFeeSplitter<WalletUnlocked>,
+    new_owner: Identity,
+) -> FuelCallResponse<()> {
+    contract.methods().transfer_ownership(new_owner).call().await.unwrap()
+}
+
+pub(crate) async fn accept_ownership(contract: &PropsFeeSplitter<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().accept_ownership().call().await.unwrap()
+}
+
+pub(crate) async fn renounce_ownership(contract: &PropsFeeSplitter<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().renounce_ownership().call().await.unwrap()
+}
+
+pub(crate) async fn pending_owner(contract: &PropsFeeSplitter<WalletUnlocked>) -> Option<Identity> {
+    contract.methods().pending_owner().call().await.unwrap().value
+}
@@ -34,6 +34,10 @@ pub(crate) fn defaults(
     )
 }
 
+pub(crate) fn other_asset() -> AssetId {
+    AssetId::new([1u8; 32])
+}
+
 pub(crate) async fn setup() -> (
     WalletUnlocked,
     WalletUnlocked,
@@ -51,7 +55,12 @@ pub(crate) async fn setup() -> (
         num_coins: number_of_coins,
         coin_amount,
     };
-    let assets = vec![base_asset];
+    let other_asset = AssetConfig {
+        id: other_asset(),
+        num_coins: number_of_coins,
+        coin_amount,
+    };
+    let assets = vec![base_asset, other_asset];
 
     let wallet_config = WalletsConfig::new_multiple_assets(number_of_wallets, assets);
     let mut wallets = launch_custom_provider_and_get_wallets(wallet_config, None, None)
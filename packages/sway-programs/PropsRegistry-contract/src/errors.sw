@@ -1 +1,35 @@
-library;
\ No newline at end of file
+library;
+
+pub enum RegisterError {
+    UnknownBytecode: (),
+    BytecodeMismatch: (),
+    OwnerMismatch: (),
+    NotContractOwner: (),
+    ConfigurableOutOfRange: (),
+    OverlappingConfigurables: (),
+    RegistryMismatch: (),
+}
+
+pub enum InitError {
+    WrongContractType: (),
+    InvalidDeploymentProof: (),
+    CallerNotOwner: (),
+}
+
+pub enum CurationError {
+    NotCurator: (),
+    NotRegistered: (),
+}
+
+pub enum MigrationError {
+    NotContractOwner: (),
+    NotRegistered: (),
+    AlreadyMigrated: (),
+    InvalidMigration: (),
+}
+
+pub enum ProtocolError {
+    InvalidPercentage: (),
+    NoPendingFeeContract: (),
+    FeeContractChangeTimelocked: (),
+}
@@ -33,3 +33,64 @@ pub struct InitEditionEvent {
     pub start_date: u64,
     pub end_date: u64,
 }
+
+pub struct InitMultiEditionEvent {
+    pub contract_id: ContractId,
+    pub owner: Identity,
+    pub name: String,
+    pub symbol: String,
+}
+
+pub struct TemplateAddedEvent {
+    pub bytecode_root: b256,
+}
+
+pub struct TemplateRemovedEvent {
+    pub bytecode_root: b256,
+}
+
+pub struct CuratorSetEvent {
+    pub curator: Identity,
+    pub enabled: bool,
+}
+
+pub struct VerifiedSetEvent {
+    pub contract_id: ContractId,
+    pub verified: bool,
+}
+
+pub struct CategorySetEvent {
+    pub contract_id: ContractId,
+    pub category: Option<String>,
+}
+
+pub struct TagsSetEvent {
+    pub contract_id: ContractId,
+    pub tags: Vec<String>,
+}
+
+pub struct ContractMigratedEvent {
+    pub old_contract_id: ContractId,
+    pub new_contract_id: ContractId,
+}
+
+pub struct ContractDeprecatedEvent {
+    pub contract_id: ContractId,
+}
+
+pub struct FeeContractChangeQueuedEvent {
+    pub fee_contract: Option<ContractId>,
+    pub effective_at: u64,
+}
+
+pub struct FeeContractSetEvent {
+    pub fee_contract: Option<ContractId>,
+}
+
+pub struct DefaultAffiliateFeeSetEvent {
+    pub percentage: u64,
+}
+
+pub struct ProtocolPausedEvent {
+    pub paused: bool,
+}
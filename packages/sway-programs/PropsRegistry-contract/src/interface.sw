@@ -2,11 +2,12 @@ library;
 
 use std::string::String;
 use std::vec::Vec;
-use standards::src7::Metadata;
+use standards::{src7::Metadata, src12::ContractConfigurables};
+use libraries::{ContractType, Deployment, PendingFeeContract, ProtocolConfig};
 
 abi PropsRegistry {
     #[storage(read, write)]
-    fn register(contractId: ContractId, owner: Identity);
+    fn register(contractId: ContractId, owner: Identity, bytecode: Vec<u8>, configurables: Option<ContractConfigurables>);
 
     #[storage(read, write)]
     fn deregister(contractId: ContractId);
@@ -15,8 +16,98 @@ abi PropsRegistry {
     fn constructor(owner: Identity);
 
     #[storage(read, write)]
-    fn init_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, start_date: u64, end_date: u64);
+    fn init_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, start_date: u64, end_date: u64, deployment: Deployment);
 
     #[storage(read, write)]
-    fn init_collection(contract_id: ContractId, owner: Identity, name: String, symbol: String, baseUri: String, price: u64, startDate: u64, endDate: u64);
-}
\ No newline at end of file
+    fn init_collection(contract_id: ContractId, owner: Identity, name: String, symbol: String, baseUri: String, price: u64, startDate: u64, endDate: u64, deployment: Deployment);
+
+    #[storage(read, write)]
+    fn init_multi_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, deployment: Deployment);
+
+    #[storage(read, write)]
+    fn add_template(bytecode_root: b256, contract_type: ContractType);
+
+    #[storage(read, write)]
+    fn remove_template(bytecode_root: b256);
+
+    #[storage(read)]
+    fn is_template(bytecode_root: b256) -> bool;
+
+    #[storage(read)]
+    fn is_registered(contract_id: ContractId) -> bool;
+
+    #[storage(read)]
+    fn owner_of(contract_id: ContractId) -> Option<Identity>;
+
+    #[storage(read)]
+    fn contract_type(contract_id: ContractId) -> Option<ContractType>;
+
+    #[storage(read)]
+    fn contracts(offset: u64, limit: u64) -> Vec<ContractId>;
+
+    #[storage(read)]
+    fn contracts_by_owner(owner: Identity, offset: u64, limit: u64) -> Vec<ContractId>;
+
+    #[storage(read)]
+    fn count() -> u64;
+
+    #[storage(read, write)]
+    fn set_curator(curator: Identity, enabled: bool);
+
+    #[storage(read)]
+    fn is_curator(identity: Identity) -> bool;
+
+    #[storage(read, write)]
+    fn set_verified(contract_id: ContractId, verified: bool);
+
+    #[storage(read)]
+    fn is_verified(contract_id: ContractId) -> bool;
+
+    #[storage(read)]
+    fn verified_contracts(offset: u64, limit: u64) -> Vec<ContractId>;
+
+    #[storage(read, write)]
+    fn set_category(contract_id: ContractId, category: Option<String>);
+
+    #[storage(read)]
+    fn category(contract_id: ContractId) -> Option<String>;
+
+    #[storage(read)]
+    fn contracts_by_category(category: String, offset: u64, limit: u64) -> Vec<ContractId>;
+
+    #[storage(read, write)]
+    fn set_tags(contract_id: ContractId, tags: Vec<String>);
+
+    #[storage(read)]
+    fn tags(contract_id: ContractId) -> Vec<String>;
+
+    #[storage(read, write)]
+    fn migrate(old_contract_id: ContractId, new_contract_id: ContractId);
+
+    #[storage(read)]
+    fn migrated_to(old_contract_id: ContractId) -> Option<ContractId>;
+
+    #[storage(read, write)]
+    fn deprecate(contract_id: ContractId);
+
+    #[storage(read)]
+    fn is_deprecated(contract_id: ContractId) -> bool;
+
+    #[storage(read, write)]
+    fn set_fee_contract(fee_contract: Option<ContractId>);
+
+    #[storage(read)]
+    fn pending_fee_contract() -> Option<PendingFeeContract>;
+
+    #[storage(read, write)]
+    fn apply_fee_contract();
+
+    #[storage(read, write)]
+    fn set_default_affiliate_fee_percentage(percentage: u64);
+
+    #[storage(read, write)]
+    fn set_protocol_paused(paused: bool);
+
+    #[storage(read)]
+    fn protocol_config() -> ProtocolConfig;
+}
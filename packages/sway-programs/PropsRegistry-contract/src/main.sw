@@ -4,24 +4,342 @@ mod errors;
 mod interface;
 mod events;
 
-use libraries::{PropsRegistry, Props721Collection, Props721Edition};
-use events::{RegisterEvent, DeregisterEvent, InitCollectionEvent, InitEditionEvent};
-use standards::{src20::SRC20, src3::SRC3, src5::{SRC5, State}, src7::{Metadata, SRC7},};
+use libraries::{ContractType, Deployment, Ownable, PendingFeeContract, ProtocolConfig, PropsRegistry, Props1155Edition, Props721Collection, Props721Edition};
+use errors::{CurationError, InitError, MigrationError, ProtocolError, RegisterError};
+use events::{RegisterEvent, DeregisterEvent, InitCollectionEvent, InitEditionEvent, InitMultiEditionEvent, TemplateAddedEvent, TemplateRemovedEvent, CuratorSetEvent, VerifiedSetEvent, CategorySetEvent, TagsSetEvent, ContractMigratedEvent, ContractDeprecatedEvent, FeeContractChangeQueuedEvent, FeeContractSetEvent, DefaultAffiliateFeeSetEvent, ProtocolPausedEvent};
+use standards::{src20::SRC20, src3::SRC3, src5::{SRC5, State}, src7::{Metadata, SRC7}, src12::ContractConfigurables,};
 use std::hash::Hash;
-use std::{hash::*, storage::storage_string::*, storage::storage_vec::*, string::String, bytes::Bytes, bytes_conversions::{b256::*, u16::*, u256::*, u32::*, u64::*,}, block::height};
+use std::{hash::*, storage::storage_string::*, storage::storage_vec::*, string::String, bytes::Bytes, bytes_conversions::{b256::*, u16::*, u256::*, u32::*, u64::*,}, block::{height, timestamp}, external::bytecode_root};
+use std::auth::msg_sender;
+use sway_libs::bytecode::compute_bytecode_root;
 
-use sway_libs::{
-    ownership::{
-        _owner,
-        initialize_ownership,
-        only_owner,
-    }
-};
+use libraries::ownership::{_accept_ownership, _renounce_ownership, _transfer_ownership};
+use sway_libs::ownership::{_owner, initialize_ownership, only_owner};
 
 /// Storage for the PropsRegistry contract
 storage {
     /// A mapping of contract IDs to their respective owners
     registry: StorageMap<ContractId, Identity> = StorageMap {},
+    /// The bytecode roots of genuine Props templates, before configurables are applied, and their contract type
+    templates: StorageMap<b256, ContractType> = StorageMap {},
+    /// The `(offset, length)` byte ranges of each template that configurables are allowed to patch
+    template_configurables: StorageMap<b256, StorageVec<(u64, u64)>> = StorageMap {},
+    /// The byte offset of each template's `REGISTRY_CONTRACT_ID` configurable, if it has one
+    template_registry_offsets: StorageMap<b256, u64> = StorageMap {},
+    /// The contract type of each registered contract
+    contract_types: StorageMap<ContractId, ContractType> = StorageMap {},
+    /// All registered contracts, in no particular order
+    contracts: StorageVec<ContractId> = StorageVec {},
+    /// The position of each registered contract in `contracts`
+    contract_index: StorageMap<ContractId, u64> = StorageMap {},
+    /// The registered contracts of each owner
+    owner_contracts: StorageMap<Identity, StorageVec<ContractId>> = StorageMap {},
+    /// The position of each registered contract in its owner's `owner_contracts` list
+    owner_contract_index: StorageMap<ContractId, u64> = StorageMap {},
+    /// Identities allowed to curate registered contracts alongside the owner
+    curators: StorageMap<Identity, bool> = StorageMap {},
+    /// Whether a registered contract has been verified by Props
+    verified: StorageMap<ContractId, bool> = StorageMap {},
+    /// All verified contracts, in no particular order
+    verified_contracts: StorageVec<ContractId> = StorageVec {},
+    /// The position of each verified contract in `verified_contracts`
+    verified_index: StorageMap<ContractId, u64> = StorageMap {},
+    /// The category of each registered contract
+    categories: StorageMap<ContractId, StorageString> = StorageMap {},
+    /// The contracts in each category, keyed by the hash of the category
+    category_contracts: StorageMap<b256, StorageVec<ContractId>> = StorageMap {},
+    /// The position of each categorized contract in its `category_contracts` list
+    category_index: StorageMap<ContractId, u64> = StorageMap {},
+    /// The free-form tags of each registered contract
+    tags: StorageMap<ContractId, StorageVec<StorageString>> = StorageMap {},
+    /// The contract that superseded each migrated contract
+    migrations: StorageMap<ContractId, ContractId> = StorageMap {},
+    /// Whether a contract has been deprecated by its owner
+    deprecated: StorageMap<ContractId, bool> = StorageMap {},
+    /// The fee splitter Props contracts pay instead of their built-in fee contract
+    fee_contract: Option<ContractId> = None,
+    /// The queued fee splitter change, if any
+    pending_fee_contract: Option<PendingFeeContract> = None,
+    /// The affiliate fee percentage used by Props contracts that do not configure their own
+    default_affiliate_fee_percentage: u64 = 0,
+    /// Whether minting is paused across all Props contracts reading from this registry
+    protocol_paused: bool = false,
+    /// The identity proposed as new owner, until it accepts
+    pending_owner: Option<Identity> = None,
+}
+
+/// The number of seconds a fee contract change is queued before it can be applied.
+///
+/// Matches the fee splitter's own timelock, so swapping the fee contract cannot bypass it.
+const FEE_CONTRACT_CHANGE_DELAY: u64 = 604_800;
+
+/// Returns the contract type of an allowlisted Props template.
+#[storage(read)]
+fn _template_type(bytecode_root: b256) -> Option<ContractType> {
+    storage.templates.get(bytecode_root).try_read()
+}
+
+/// Reverts unless every patch in `configurables` lies within a configurable range of the template,
+/// and no two patches overlap.
+///
+/// Configurables are raw `(offset, data)` patches, so without this check they could rewrite the
+/// template's code and still be accepted as genuine.
+#[storage(read)]
+fn _require_allowed_configurables(template_root: b256, configurables: ContractConfigurables) {
+    let ranges = storage.template_configurables.get(template_root);
+
+    let mut i = 0;
+    while i < configurables.len() {
+        let (offset, data) = configurables.get(i).unwrap();
+        let end = offset + data.len();
+
+        let mut allowed = false;
+        let mut j = 0;
+        while j < ranges.len() {
+            let (start, length) = ranges.get(j).unwrap().read();
+            if offset >= start && end <= start + length {
+                allowed = true;
+                break;
+            }
+            j += 1;
+        }
+        require(allowed, RegisterError::ConfigurableOutOfRange);
+
+        let mut k = i + 1;
+        while k < configurables.len() {
+            let (other_offset, other_data) = configurables.get(k).unwrap();
+            require(
+                end <= other_offset || other_offset + other_data.len() <= offset,
+                RegisterError::OverlappingConfigurables,
+            );
+            k += 1;
+        }
+
+        i += 1;
+    }
+}
+
+/// Returns the `REGISTRY_CONTRACT_ID` a deployment of `template_root` was configured with, or `None`
+/// if the template has no such configurable.
+///
+/// The 32 bytes at the template's registry offset are read from `bytecode`, then overwritten by
+/// any bytes of `configurables` that patch them.
+#[storage(read)]
+fn _configured_registry(template_root: b256, bytecode: Vec<u8>, configurables: Option<ContractConfigurables>) -> Option<ContractId> {
+    let offset = match storage.template_registry_offsets.get(template_root).try_read() {
+        Some(offset) => offset,
+        None => return None,
+    };
+
+    let mut registry = Bytes::new();
+    let mut i = 0;
+    while i < 32 {
+        registry.push(bytecode.get(offset + i).unwrap());
+        i += 1;
+    }
+
+    match configurables {
+        Some(patches) => {
+            let mut i = 0;
+            while i < patches.len() {
+                let (patch_offset, data) = patches.get(i).unwrap();
+                let mut j = 0;
+                while j < data.len() {
+                    let position = patch_offset + j;
+                    if position >= offset && position < offset + 32 {
+                        registry.set(position - offset, data.get(j).unwrap());
+                    }
+                    j += 1;
+                }
+                i += 1;
+            }
+        },
+        None => {},
+    }
+
+    Some(ContractId::from(b256::from_be_bytes(registry)))
+}
+
+/// Reverts unless `contract_id` was deployed from an allowlisted template, and returns the template's type.
+///
+/// The template root is computed from the unconfigured `bytecode`; the configurables are then
+/// checked against the template's configurable ranges, swapped in, and the result must match
+/// the root of the deployed contract. A template with a `REGISTRY_CONTRACT_ID` configurable must
+/// have been deployed pointing at this registry, so it reads its protocol config from here.
+/// `registry_required` additionally rejects templates without one.
+#[storage(read)]
+fn _require_genuine_bytecode(contract_id: ContractId, bytecode: Vec<u8>, configurables: Option<ContractConfigurables>, registry_required: bool) -> ContractType {
+    let mut bytecode = bytecode;
+    let template_root = compute_bytecode_root(bytecode, None);
+    let contract_type = _template_type(template_root);
+    require(contract_type.is_some(), RegisterError::UnknownBytecode);
+
+    match _configured_registry(template_root, bytecode, configurables) {
+        Some(registry) => require(registry == ContractId::this(), RegisterError::RegistryMismatch),
+        None => require(!registry_required, RegisterError::RegistryMismatch),
+    }
+
+    let configured_root = match configurables {
+        Some(patches) => {
+            _require_allowed_configurables(template_root, patches);
+            compute_bytecode_root(bytecode, configurables)
+        },
+        None => template_root,
+    };
+    require(configured_root == bytecode_root(contract_id), RegisterError::BytecodeMismatch);
+
+    contract_type.unwrap()
+}
+
+/// Computes the id of a contract deployed with `salt`, following the Fuel contract id derivation.
+fn _contract_id(salt: b256, bytecode_root: b256, state_root: b256) -> ContractId {
+    // The id is seeded with the bytes of "FUEL"
+    ContractId::from(sha256(((0x46u8, 0x55u8, 0x45u8, 0x4Cu8), salt, bytecode_root, state_root)))
+}
+
+/// Reverts unless `owner` is calling and `contract_id` is a fresh deployment of a `contract_type` template made for `owner`.
+///
+/// The deployment salt has to be derived from the owner, so a deployment can only ever be
+/// initialized for the owner it was made for. Requiring the owner to call stops anyone from
+/// replaying the public proof with their own initialization parameters.
+#[storage(read)]
+fn _require_initializable(contract_id: ContractId, owner: Identity, deployment: Deployment, contract_type: ContractType) {
+    require(msg_sender().unwrap() == owner, InitError::CallerNotOwner);
+
+    require(
+        _require_genuine_bytecode(contract_id, deployment.bytecode, deployment.configurables, true) == contract_type,
+        InitError::WrongContractType,
+    );
+
+    let salt = sha256((owner.bits(), deployment.salt));
+    require(
+        _contract_id(salt, bytecode_root(contract_id), deployment.state_root) == contract_id,
+        InitError::InvalidDeploymentProof,
+    );
+}
+
+/// Adds a contract the registry has just initialized.
+#[storage(read, write)]
+fn _register_initialized(contract_id: ContractId, owner: Identity, contract_type: ContractType) {
+    _add_to_index(contract_id, owner);
+    storage.registry.insert(contract_id, owner);
+    storage.contract_types.insert(contract_id, contract_type);
+
+    // Log the RegisterEvent
+    log(RegisterEvent {
+        contract_id: contract_id,
+        owner: owner,
+    });
+}
+
+/// Appends `contract_id` to `list` and records its position.
+#[storage(read, write)]
+fn _push_index(list: StorageKey<StorageVec<ContractId>>, positions: StorageKey<StorageMap<ContractId, u64>>, contract_id: ContractId) {
+    positions.insert(contract_id, list.len());
+    list.push(contract_id);
+}
+
+/// Removes `contract_id` from `list`.
+///
+/// The last entry is moved into the freed slot, so removal is constant time.
+#[storage(read, write)]
+fn _remove_index(list: StorageKey<StorageVec<ContractId>>, positions: StorageKey<StorageMap<ContractId, u64>>, contract_id: ContractId) {
+    let index = positions.get(contract_id).read();
+    let _ = list.swap_remove(index);
+    if index < list.len() {
+        positions.insert(list.get(index).unwrap().read(), index);
+    }
+    let _ = positions.remove(contract_id);
+}
+
+/// Appends `contract_id` to the global index and to the index of `owner`.
+#[storage(read, write)]
+fn _add_to_index(contract_id: ContractId, owner: Identity) {
+    _push_index(storage.contracts, storage.contract_index, contract_id);
+    _push_index(storage.owner_contracts.get(owner), storage.owner_contract_index, contract_id);
+}
+
+/// Removes `contract_id` from the global index, the index of `owner` and the curation indexes.
+#[storage(read, write)]
+fn _remove_from_index(contract_id: ContractId, owner: Identity) {
+    _remove_index(storage.contracts, storage.contract_index, contract_id);
+    _remove_index(storage.owner_contracts.get(owner), storage.owner_contract_index, contract_id);
+
+    if storage.verified.get(contract_id).try_read().unwrap_or(false) {
+        _remove_index(storage.verified_contracts, storage.verified_index, contract_id);
+        let _ = storage.verified.remove(contract_id);
+    }
+    match storage.categories.get(contract_id).read_slice() {
+        Some(category) => {
+            _remove_index(storage.category_contracts.get(sha256(category)), storage.category_index, contract_id);
+            let _ = storage.categories.get(contract_id).clear();
+        },
+        None => (),
+    }
+    let _ = storage.tags.get(contract_id).clear();
+}
+
+/// Moves `contract_id` from the index of `previous_owner` to the index of `owner`.
+#[storage(read, write)]
+fn _move_owner_index(contract_id: ContractId, previous_owner: Identity, owner: Identity) {
+    _remove_index(storage.owner_contracts.get(previous_owner), storage.owner_contract_index, contract_id);
+    _push_index(storage.owner_contracts.get(owner), storage.owner_contract_index, contract_id);
+}
+
+/// Reverts unless the caller is the registry owner or a curator.
+#[storage(read)]
+fn _only_curator() {
+    let sender = msg_sender().unwrap();
+    require(
+        _owner() == State::Initialized(sender) || storage.curators.get(sender).try_read().unwrap_or(false),
+        CurationError::NotCurator,
+    );
+}
+
+/// Reverts unless `contract_id` is registered and the caller is its current SRC-5 owner.
+///
+/// The owner is read from the contract itself, since the registered owner goes stale after an ownership transfer.
+#[storage(read)]
+fn _only_contract_owner(contract_id: ContractId) {
+    require(storage.registry.get(contract_id).try_read().is_some(), MigrationError::NotRegistered);
+
+    let ownership = abi(SRC5, contract_id.into());
+    require(ownership.owner() == State::Initialized(msg_sender().unwrap()), MigrationError::NotContractOwner);
+}
+
+/// Marks `contract_id` as deprecated.
+#[storage(write)]
+fn _deprecate(contract_id: ContractId) {
+    storage.deprecated.insert(contract_id, true);
+
+    log(ContractDeprecatedEvent { contract_id });
+}
+
+/// Reverts unless `contract_id` is registered.
+#[storage(read)]
+fn _require_registered(contract_id: ContractId) {
+    require(storage.registry.get(contract_id).try_read().is_some(), CurationError::NotRegistered);
+}
+
+/// Returns up to `limit` entries of `list`, starting at `offset`.
+#[storage(read)]
+fn _page(list: StorageKey<StorageVec<ContractId>>, offset: u64, limit: u64) -> Vec<ContractId> {
+    let mut page = Vec::new();
+    let len = list.len();
+
+    let mut i = offset;
+    while i < len && i - offset < limit {
+        page.push(list.get(i).unwrap().read());
+        i += 1;
+    }
+
+    page
+}
+
+/// Reverts unless `owner` is the SRC-5 owner of `contract_id`.
+fn _require_contract_owner(contract_id: ContractId, owner: Identity) {
+    let ownership = abi(SRC5, contract_id.into());
+    require(ownership.owner() == State::Initialized(owner), RegisterError::OwnerMismatch);
 }
 
 impl SRC5 for Contract {
@@ -56,20 +374,186 @@ impl SRC5 for Contract {
     }
 }
 
+impl Ownable for Contract {
+    /// Proposes a new owner for the contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Ownership only changes once `new_owner` calls `accept_ownership`. Proposing another
+    /// identity replaces the pending proposal.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner`: [Identity] - The identity proposed as new owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    ///
+    /// fn foo(contract_id: ContractId, new_owner: Identity) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.transfer_ownership(new_owner);
+    ///     assert(ownable_abi.pending_owner() == Some(new_owner));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        _transfer_ownership(new_owner, storage.pending_owner);
+    }
+
+    /// Accepts a pending ownership transfer, making the caller the owner.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the pending owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    /// use standards::src5::{SRC5, State};
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.accept_ownership();
+    ///     assert(abi(SRC5, contract_id).owner() == State::Initialized(msg_sender().unwrap()));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn accept_ownership() {
+        _accept_ownership(storage.pending_owner);
+    }
+
+    /// Permanently renounces ownership of the contract.
+    ///
+    /// # Additional Information
+    ///
+    /// Any pending ownership transfer is cancelled. Owner-only functions can no longer be called afterwards.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the contract owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    /// use standards::src5::{SRC5, State};
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     ownable_abi.renounce_ownership();
+    ///     assert(abi(SRC5, contract_id).owner() == State::Revoked);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn renounce_ownership() {
+        _renounce_ownership(storage.pending_owner);
+    }
+
+    /// Returns the identity that has been proposed as new owner.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Identity>] - The pending owner, or `None` if no transfer is pending.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::Ownable;
+    ///
+    /// fn foo(contract_id: ContractId) {
+    ///     let ownable_abi = abi(Ownable, contract_id);
+    ///     assert(ownable_abi.pending_owner().is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity> {
+        storage.pending_owner.read()
+    }
+}
+
 impl PropsRegistry for Contract {
-    /// Registers a contract with its owner
+    /// Registers a genuine Props contract with its owner.
+    ///
+    /// # Additional Information
+    ///
+    /// Registration is permissionless for new entries, but an existing entry can only be
+    /// overwritten by the contract's current owner.
     ///
     /// # Arguments
     ///
-    /// * `contractId`: The ID of the contract to be registered
-    /// * `owner`: The Identity of the contract owner
+    /// * `contractId`: [ContractId] - The ID of the contract to be registered.
+    /// * `owner`: [Identity] - The owner of the contract, as reported by its SRC-5 `owner()`.
+    /// * `bytecode`: [Vec<u8>] - The template bytecode the contract was deployed from.
+    /// * `configurables`: [Option<ContractConfigurables>] - The configurables applied at deployment, if any.
     ///
-    /// # Effects
+    /// # Reverts
+    ///
+    /// * When the root of `bytecode` is not an allowlisted template.
+    /// * When a patch in `configurables` lies outside the template's configurable ranges.
+    /// * When two patches in `configurables` overlap.
+    /// * When `bytecode` with `configurables` applied does not match the deployed contract.
+    /// * When the template has a `REGISTRY_CONTRACT_ID` configurable that is not set to this registry.
+    /// * When `owner` is not the SRC-5 owner of the contract.
+    /// * When the contract is already registered and the caller is not `owner`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `5`
+    /// * Writes: `6`
+    ///
+    /// # Examples
     ///
-    /// Inserts the contract ID and owner pair into the registry
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId, owner: Identity, bytecode: Vec<u8>) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.register(contract_id, owner, bytecode, None);
+    /// }
+    /// ```
     #[storage(read, write)]
-    fn register(contractId: ContractId, owner: Identity) {
+    fn register(contractId: ContractId, owner: Identity, bytecode: Vec<u8>, configurables: Option<ContractConfigurables>) {
+        let contract_type = _require_genuine_bytecode(contractId, bytecode, configurables, false);
+        _require_contract_owner(contractId, owner);
+
+        match storage.registry.get(contractId).try_read() {
+            Some(previous_owner) => {
+                require(msg_sender().unwrap() == owner, RegisterError::NotContractOwner);
+                if previous_owner != owner {
+                    _move_owner_index(contractId, previous_owner, owner);
+                }
+            },
+            None => _add_to_index(contractId, owner),
+        }
+
         storage.registry.insert(contractId, owner);
+        storage.contract_types.insert(contractId, contract_type);
 
         // Log the RegisterEvent
         log(RegisterEvent {
@@ -86,11 +570,16 @@ impl PropsRegistry for Contract {
     ///
     /// # Effects
     ///
-    /// Removes the contract ID from the registry
+    /// Removes the contract ID from the registry, from the contract and owner indexes, and clears its curation data
     #[storage(read, write)]
     fn deregister(contractId: ContractId) {
         only_owner();
+        match storage.registry.get(contractId).try_read() {
+            Some(owner) => _remove_from_index(contractId, owner),
+            None => (),
+        }
         storage.registry.remove(contractId);
+        let _ = storage.contract_types.remove(contractId);
 
         // Log the DeregisterEvent
         log(DeregisterEvent {
@@ -133,10 +622,11 @@ impl PropsRegistry for Contract {
         initialize_ownership(owner);
     }
 
-    /// Initializes a new edition in the registry.
+    /// Initializes a new edition and registers it.
     ///
     /// # Arguments
     ///
+    /// * `contract_id`: [ContractId] - The edition contract to initialize.
     /// * `owner`: [Identity] - The owner of the edition.
     /// * `name`: [String] - The name of the edition.
     /// * `symbol`: [String] - The symbol of the edition.
@@ -145,18 +635,28 @@ impl PropsRegistry for Contract {
     /// * `price`: [u64] - The price of the edition.
     /// * `start_date`: [u64] - The start date of the edition.
     /// * `end_date`: [u64] - The end date of the edition.
+    /// * `deployment`: [Deployment] - Proof that the contract is a genuine edition deployed for `owner`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract was not deployed from an allowlisted edition template.
+    /// * When the contract's `REGISTRY_CONTRACT_ID` is not this registry.
+    /// * When the caller is not `owner`.
+    /// * When the deployment salt was not derived from `owner`.
+    /// * When the contract has been initialized before.
     ///
     /// # Effects
     ///
-    /// Initializes a new edition with the given parameters.
+    /// Initializes a new edition with the given parameters and registers it as an edition of `owner`.
     #[storage(read, write)]
-    fn init_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, start_date: u64, end_date: u64) {
-        // Implementation details would go here
-        // This might involve creating a new Props721Edition contract and registering it
+    fn init_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, start_date: u64, end_date: u64, deployment: Deployment) {
+        _require_initializable(contract_id, owner, deployment, ContractType::Edition);
+
+        let edition = abi(Props721Edition, contract_id.into());
+        edition.constructor(owner, name, symbol, metadata_keys, metadata_values, price, start_date, end_date);
+
+        _register_initialized(contract_id, owner, ContractType::Edition);
 
-        let registry = abi(Props721Edition, contract_id.into());
-        registry.constructor(owner, name, symbol, metadata_keys, metadata_values, price, start_date, end_date);
-        
         // Log the InitEditionEvent
         log(InitEditionEvent {
             contract_id: contract_id,
@@ -171,10 +671,11 @@ impl PropsRegistry for Contract {
         });
     }
 
-    /// Initializes a new collection in the registry.
+    /// Initializes a new collection and registers it.
     ///
     /// # Arguments
     ///
+    /// * `contract_id`: [ContractId] - The collection contract to initialize.
     /// * `owner`: [Identity] - The owner of the collection.
     /// * `name`: [String] - The name of the collection.
     /// * `symbol`: [String] - The symbol of the collection.
@@ -182,15 +683,27 @@ impl PropsRegistry for Contract {
     /// * `price`: [u64] - The price of the collection.
     /// * `startDate`: [u64] - The start date of the collection.
     /// * `endDate`: [u64] - The end date of the collection.
+    /// * `deployment`: [Deployment] - Proof that the contract is a genuine collection deployed for `owner`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract was not deployed from an allowlisted collection template.
+    /// * When the contract's `REGISTRY_CONTRACT_ID` is not this registry.
+    /// * When the caller is not `owner`.
+    /// * When the deployment salt was not derived from `owner`.
+    /// * When the contract has been initialized before.
     ///
     /// # Effects
     ///
-    /// Initializes a new collection with the given parameters.
+    /// Initializes a new collection with the given parameters and registers it as a collection of `owner`.
     #[storage(read, write)]
-    fn init_collection(contract_id: ContractId, owner: Identity, name: String, symbol: String, baseUri: String, price: u64, startDate: u64, endDate: u64) {
+    fn init_collection(contract_id: ContractId, owner: Identity, name: String, symbol: String, baseUri: String, price: u64, startDate: u64, endDate: u64, deployment: Deployment) {
+        _require_initializable(contract_id, owner, deployment, ContractType::Collection);
 
-        let registry = abi(Props721Collection, contract_id.into());
-        registry.constructor(owner, name, symbol, baseUri, price, startDate, endDate);
+        let collection = abi(Props721Collection, contract_id.into());
+        collection.constructor(owner, name, symbol, baseUri, price, startDate, endDate);
+
+        _register_initialized(contract_id, owner, ContractType::Collection);
 
         // Log the InitCollectionEvent
         log(InitCollectionEvent {
@@ -204,4 +717,1090 @@ impl PropsRegistry for Contract {
             end_date: endDate,
         });
     }
+
+    /// Initializes a new multi-edition contract and registers it.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The multi-edition contract to initialize.
+    /// * `owner`: [Identity] - The owner of the contract.
+    /// * `name`: [String] - The name shared by all editions.
+    /// * `symbol`: [String] - The symbol shared by all editions.
+    /// * `deployment`: [Deployment] - Proof that the contract is a genuine multi-edition contract deployed for `owner`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract was not deployed from an allowlisted multi-edition template.
+    /// * When the contract's `REGISTRY_CONTRACT_ID` is not this registry.
+    /// * When the caller is not `owner`.
+    /// * When the deployment salt was not derived from `owner`.
+    /// * When the contract has been initialized before.
+    ///
+    /// # Effects
+    ///
+    /// Initializes a new multi-edition contract with the given parameters. Editions are created afterwards by the owner.
+    #[storage(read, write)]
+    fn init_multi_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, deployment: Deployment) {
+        _require_initializable(contract_id, owner, deployment, ContractType::MultiEdition);
+
+        let multi_edition = abi(Props1155Edition, contract_id.into());
+        multi_edition.constructor(owner, name, symbol);
+
+        _register_initialized(contract_id, owner, ContractType::MultiEdition);
+
+        // Log the InitMultiEditionEvent
+        log(InitMultiEditionEvent {
+            contract_id: contract_id,
+            owner: owner,
+            name: name,
+            symbol: symbol,
+        });
+    }
+
+    /// Adds a Props template to the bytecode allowlist.
+    ///
+    /// # Additional Information
+    ///
+    /// Configurables supplied on registration or initialization may only patch bytes inside
+    /// `configurable_ranges`. Contracts deployed from a template with a `registry_offset` are only
+    /// accepted when their `REGISTRY_CONTRACT_ID` is this registry, and only such templates can be
+    /// initialized. Adding a template again replaces its ranges and offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytecode_root`: [b256] - The bytecode root of the template, without configurables applied.
+    /// * `contract_type`: [ContractType] - The type of contract deployed from the template.
+    /// * `configurable_ranges`: [Vec<(u64, u64)>] - The `(offset, length)` byte ranges of the template's configurables section.
+    /// * `registry_offset`: [Option<u64>] - The byte offset of the template's `REGISTRY_CONTRACT_ID` configurable, if it has one.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the registry owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `3 + configurable_ranges.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::{ContractType, PropsRegistry};
+    ///
+    /// fn foo(registry_id: ContractId, bytecode_root: b256) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.add_template(bytecode_root, ContractType::Edition, Vec::new(), None);
+    ///     assert(registry_abi.is_template(bytecode_root));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn add_template(bytecode_root: b256, contract_type: ContractType, configurable_ranges: Vec<(u64, u64)>, registry_offset: Option<u64>) {
+        only_owner();
+        storage.templates.insert(bytecode_root, contract_type);
+
+        let ranges = storage.template_configurables.get(bytecode_root);
+        let _ = ranges.clear();
+        let mut i = 0;
+        while i < configurable_ranges.len() {
+            ranges.push(configurable_ranges.get(i).unwrap());
+            i += 1;
+        }
+
+        match registry_offset {
+            Some(offset) => storage.template_registry_offsets.insert(bytecode_root, offset),
+            None => {
+                let _ = storage.template_registry_offsets.remove(bytecode_root);
+            },
+        }
+
+        log(TemplateAddedEvent { bytecode_root });
+    }
+
+    /// Removes a Props template from the bytecode allowlist.
+    ///
+    /// # Additional Information
+    ///
+    /// Contracts that were already registered from this template stay registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytecode_root`: [b256] - The bytecode root of the template to remove.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the registry owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, bytecode_root: b256) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.remove_template(bytecode_root);
+    ///     assert(!registry_abi.is_template(bytecode_root));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn remove_template(bytecode_root: b256) {
+        only_owner();
+        let _ = storage.templates.remove(bytecode_root);
+        let _ = storage.template_configurables.get(bytecode_root).clear();
+        let _ = storage.template_registry_offsets.remove(bytecode_root);
+
+        log(TemplateRemovedEvent { bytecode_root });
+    }
+
+    /// Returns whether a bytecode root is an allowlisted Props template.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytecode_root`: [b256] - The bytecode root to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if contracts deployed from this template can be registered.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, bytecode_root: b256) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(!registry_abi.is_template(bytecode_root));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn is_template(bytecode_root: b256) -> bool {
+        _template_type(bytecode_root).is_some()
+    }
+
+    /// Returns whether a contract is registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the contract is registered.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(!registry_abi.is_registered(contract_id));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn is_registered(contract_id: ContractId) -> bool {
+        storage.registry.get(contract_id).try_read().is_some()
+    }
+
+    /// Returns the registered owner of a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to look up.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Identity>] - The owner the contract was registered with, or `None` if it is not registered.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId, owner: Identity) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(registry_abi.owner_of(contract_id) == Some(owner));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn owner_of(contract_id: ContractId) -> Option<Identity> {
+        storage.registry.get(contract_id).try_read()
+    }
+
+    /// Returns the type of a registered contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to look up.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<ContractType>] - The type of the template the contract was deployed from, or `None` if it is not registered.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::{ContractType, PropsRegistry};
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(registry_abi.contract_type(contract_id) == Some(ContractType::Edition));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn contract_type(contract_id: ContractId) -> Option<ContractType> {
+        storage.contract_types.get(contract_id).try_read()
+    }
+
+    /// Returns a page of registered contracts.
+    ///
+    /// # Additional Information
+    ///
+    /// Deregistering a contract moves the last contract into its position, so the order is not stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: [u64] - The index of the first contract to return.
+    /// * `limit`: [u64] - The maximum number of contracts to return.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<ContractId>] - The registered contracts in the requested range.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1 + limit`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     let contracts = registry_abi.contracts(0, 10);
+    ///     assert(contracts.len() <= 10);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn contracts(offset: u64, limit: u64) -> Vec<ContractId> {
+        _page(storage.contracts, offset, limit)
+    }
+
+    /// Returns a page of the contracts registered to an owner.
+    ///
+    /// # Additional Information
+    ///
+    /// Deregistering or re-registering a contract moves the owner's last contract into its position, so the order is not stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner`: [Identity] - The owner whose contracts are returned.
+    /// * `offset`: [u64] - The index of the first contract to return.
+    /// * `limit`: [u64] - The maximum number of contracts to return.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<ContractId>] - The owner's registered contracts in the requested range.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1 + limit`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, owner: Identity) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     let contracts = registry_abi.contracts_by_owner(owner, 0, 10);
+    ///     assert(contracts.len() <= 10);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn contracts_by_owner(owner: Identity, offset: u64, limit: u64) -> Vec<ContractId> {
+        _page(storage.owner_contracts.get(owner), offset, limit)
+    }
+
+    /// Returns the number of registered contracts.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of registered contracts.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(registry_abi.count() == 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn count() -> u64 {
+        storage.contracts.len()
+    }
+
+    /// Grants or revokes the curator role.
+    ///
+    /// # Additional Information
+    ///
+    /// Curators can verify, categorize and tag registered contracts. The owner can always curate.
+    ///
+    /// # Arguments
+    ///
+    /// * `curator`: [Identity] - The identity to update.
+    /// * `enabled`: [bool] - Whether `curator` may curate contracts.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the registry owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, curator: Identity) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.set_curator(curator, true);
+    ///     assert(registry_abi.is_curator(curator));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_curator(curator: Identity, enabled: bool) {
+        only_owner();
+        storage.curators.insert(curator, enabled);
+
+        log(CuratorSetEvent { curator, enabled });
+    }
+
+    /// Returns whether an identity has been granted the curator role.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: [Identity] - The identity to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if `identity` is a curator.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, identity: Identity) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(!registry_abi.is_curator(identity));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn is_curator(identity: Identity) -> bool {
+        storage.curators.get(identity).try_read().unwrap_or(false)
+    }
+
+    /// Marks a registered contract as verified by Props, or removes the mark.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The registered contract.
+    /// * `verified`: [bool] - Whether the contract is verified.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is neither the registry owner nor a curator.
+    /// * When the contract is not registered.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `4`
+    /// * Writes: `3`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.set_verified(contract_id, true);
+    ///     assert(registry_abi.is_verified(contract_id));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_verified(contract_id: ContractId, verified: bool) {
+        _only_curator();
+        _require_registered(contract_id);
+
+        let was_verified = storage.verified.get(contract_id).try_read().unwrap_or(false);
+        if verified && !was_verified {
+            _push_index(storage.verified_contracts, storage.verified_index, contract_id);
+            storage.verified.insert(contract_id, true);
+        } else if !verified && was_verified {
+            _remove_index(storage.verified_contracts, storage.verified_index, contract_id);
+            let _ = storage.verified.remove(contract_id);
+        }
+
+        log(VerifiedSetEvent { contract_id, verified });
+    }
+
+    /// Returns whether a contract has been verified by Props.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the contract is registered and verified.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(!registry_abi.is_verified(contract_id));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn is_verified(contract_id: ContractId) -> bool {
+        storage.verified.get(contract_id).try_read().unwrap_or(false)
+    }
+
+    /// Returns a page of verified contracts.
+    ///
+    /// # Additional Information
+    ///
+    /// Unverifying a contract moves the last verified contract into its position, so the order is not stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: [u64] - The index of the first contract to return.
+    /// * `limit`: [u64] - The maximum number of contracts to return.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<ContractId>] - The verified contracts in the requested range.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1 + limit`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     let verified = registry_abi.verified_contracts(0, 10);
+    ///     assert(verified.len() <= 10);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn verified_contracts(offset: u64, limit: u64) -> Vec<ContractId> {
+        _page(storage.verified_contracts, offset, limit)
+    }
+
+    /// Sets or clears the category of a registered contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The registered contract.
+    /// * `category`: [Option<String>] - The new category, or `None` to remove it.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is neither the registry owner nor a curator.
+    /// * When the contract is not registered.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `5`
+    /// * Writes: `5`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.set_category(contract_id, Some(String::from_ascii_str("art")));
+    ///     assert(registry_abi.category(contract_id) == Some(String::from_ascii_str("art")));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_category(contract_id: ContractId, category: Option<String>) {
+        _only_curator();
+        _require_registered(contract_id);
+
+        match storage.categories.get(contract_id).read_slice() {
+            Some(previous) => {
+                _remove_index(storage.category_contracts.get(sha256(previous)), storage.category_index, contract_id);
+                let _ = storage.categories.get(contract_id).clear();
+            },
+            None => (),
+        }
+
+        match category {
+            Some(new_category) => {
+                _push_index(storage.category_contracts.get(sha256(new_category)), storage.category_index, contract_id);
+                storage.categories.get(contract_id).write_slice(new_category);
+            },
+            None => (),
+        }
+
+        log(CategorySetEvent { contract_id, category });
+    }
+
+    /// Returns the category of a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to look up.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<String>] - The category, or `None` if the contract has not been categorized.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(registry_abi.category(contract_id).is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn category(contract_id: ContractId) -> Option<String> {
+        storage.categories.get(contract_id).read_slice()
+    }
+
+    /// Returns a page of the contracts in a category.
+    ///
+    /// # Additional Information
+    ///
+    /// Moving a contract out of the category moves the category's last contract into its position, so the order is not stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `category`: [String] - The category to filter by.
+    /// * `offset`: [u64] - The index of the first contract to return.
+    /// * `limit`: [u64] - The maximum number of contracts to return.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<ContractId>] - The contracts in the category in the requested range.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1 + limit`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     let art = registry_abi.contracts_by_category(String::from_ascii_str("art"), 0, 10);
+    ///     assert(art.len() <= 10);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn contracts_by_category(category: String, offset: u64, limit: u64) -> Vec<ContractId> {
+        _page(storage.category_contracts.get(sha256(category)), offset, limit)
+    }
+
+    /// Replaces the tags of a registered contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The registered contract.
+    /// * `tags`: [Vec<String>] - The new tags. An empty list removes all tags.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is neither the registry owner nor a curator.
+    /// * When the contract is not registered.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    /// * Writes: `1 + 2 * tags.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     let mut tags = Vec::new();
+    ///     tags.push(String::from_ascii_str("pixel"));
+    ///     registry_abi.set_tags(contract_id, tags);
+    ///     assert(registry_abi.tags(contract_id).len() == 1);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_tags(contract_id: ContractId, tags: Vec<String>) {
+        _only_curator();
+        _require_registered(contract_id);
+
+        let _ = storage.tags.get(contract_id).clear();
+        let mut i = 0;
+        while i < tags.len() {
+            storage.tags.get(contract_id).push(StorageString {});
+            storage.tags.get(contract_id).get(i).unwrap().write_slice(tags.get(i).unwrap());
+            i += 1;
+        }
+
+        log(TagsSetEvent { contract_id, tags });
+    }
+
+    /// Returns the tags of a contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to look up.
+    ///
+    /// # Returns
+    ///
+    /// * [Vec<String>] - The tags, in the order they were set.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1 + number of tags`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(registry_abi.tags(contract_id).is_empty());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn tags(contract_id: ContractId) -> Vec<String> {
+        let mut tags = Vec::new();
+        let len = storage.tags.get(contract_id).len();
+
+        let mut i = 0;
+        while i < len {
+            tags.push(storage.tags.get(contract_id).get(i).unwrap().read_slice().unwrap());
+            i += 1;
+        }
+
+        tags
+    }
+
+    /// Points a registered contract to the contract that superseded it.
+    ///
+    /// # Additional Information
+    ///
+    /// The old contract is deprecated as part of the migration. Migrations form a chain that clients
+    /// can follow with `migrated_to` until it returns `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_contract_id`: [ContractId] - The superseded contract.
+    /// * `new_contract_id`: [ContractId] - The contract that replaces it.
+    ///
+    /// # Reverts
+    ///
+    /// * When either contract is not registered.
+    /// * When the caller is not the current owner of both contracts.
+    /// * When the old contract has already been migrated.
+    /// * When the new contract is the old contract or has itself been migrated.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `4`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, old_contract_id: ContractId, new_contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.migrate(old_contract_id, new_contract_id);
+    ///     assert(registry_abi.migrated_to(old_contract_id) == Some(new_contract_id));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn migrate(old_contract_id: ContractId, new_contract_id: ContractId) {
+        _only_contract_owner(old_contract_id);
+        _only_contract_owner(new_contract_id);
+        require(storage.migrations.get(old_contract_id).try_read().is_none(), MigrationError::AlreadyMigrated);
+        require(
+            old_contract_id != new_contract_id && storage.migrations.get(new_contract_id).try_read().is_none(),
+            MigrationError::InvalidMigration,
+        );
+
+        storage.migrations.insert(old_contract_id, new_contract_id);
+
+        log(ContractMigratedEvent {
+            old_contract_id,
+            new_contract_id,
+        });
+
+        _deprecate(old_contract_id);
+    }
+
+    /// Returns the contract that superseded a migrated contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_contract_id`: [ContractId] - The contract to look up.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<ContractId>] - The next contract in the migration chain, or `None` if the contract has not been migrated.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) -> ContractId {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     let mut current = contract_id;
+    ///     let mut next = registry_abi.migrated_to(current);
+    ///     while next.is_some() {
+    ///         current = next.unwrap();
+    ///         next = registry_abi.migrated_to(current);
+    ///     }
+    ///     current
+    /// }
+    /// ```
+    #[storage(read)]
+    fn migrated_to(old_contract_id: ContractId) -> Option<ContractId> {
+        storage.migrations.get(old_contract_id).try_read()
+    }
+
+    /// Marks a registered contract as deprecated without pointing to a replacement.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to deprecate.
+    ///
+    /// # Reverts
+    ///
+    /// * When the contract is not registered.
+    /// * When the caller is not the current owner of the contract.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.deprecate(contract_id);
+    ///     assert(registry_abi.is_deprecated(contract_id));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn deprecate(contract_id: ContractId) {
+        _only_contract_owner(contract_id);
+        _deprecate(contract_id);
+    }
+
+    /// Returns whether a contract has been deprecated, either directly or by migrating it.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id`: [ContractId] - The contract to check.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if the contract is deprecated.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, contract_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(!registry_abi.is_deprecated(contract_id));
+    /// }
+    /// ```
+    #[storage(read)]
+    fn is_deprecated(contract_id: ContractId) -> bool {
+        storage.deprecated.get(contract_id).try_read().unwrap_or(false)
+    }
+
+    /// Queues a change of the fee splitter that Props contracts pay at mint time.
+    ///
+    /// # Additional Information
+    ///
+    /// Only contracts deployed with this registry as their `REGISTRY_CONTRACT_ID` read this setting.
+    /// Passing `None` makes them fall back to their built-in fee contract. A different fee contract
+    /// can charge any fee, so the change only applies once `apply_fee_contract` is called after
+    /// `FEE_CONTRACT_CHANGE_DELAY` seconds. A new change replaces the queued one.
+    ///
+    /// # Arguments
+    ///
+    /// * `fee_contract`: [Option<ContractId>] - The fee splitter to use, if any.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the registry owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId, fee_contract: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.set_fee_contract(Some(fee_contract));
+    ///     assert(registry_abi.pending_fee_contract().unwrap().fee_contract == Some(fee_contract));
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_fee_contract(fee_contract: Option<ContractId>) {
+        only_owner();
+        let effective_at = timestamp() + FEE_CONTRACT_CHANGE_DELAY;
+        storage.pending_fee_contract.write(Some(PendingFeeContract { fee_contract, effective_at }));
+
+        log(FeeContractChangeQueuedEvent { fee_contract, effective_at });
+    }
+
+    /// Returns the queued fee splitter change.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<PendingFeeContract>] - The queued fee splitter and when it can be applied, or `None`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(registry_abi.pending_fee_contract().is_none());
+    /// }
+    /// ```
+    #[storage(read)]
+    fn pending_fee_contract() -> Option<PendingFeeContract> {
+        storage.pending_fee_contract.read()
+    }
+
+    /// Applies the queued fee splitter change.
+    ///
+    /// # Additional Information
+    ///
+    /// Anyone can call this function once the delay has passed.
+    ///
+    /// # Reverts
+    ///
+    /// * When no fee splitter change is queued.
+    /// * When the queued change is not effective yet.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `2`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.apply_fee_contract();
+    ///     assert(registry_abi.pending_fee_contract().is_none());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn apply_fee_contract() {
+        let pending = storage.pending_fee_contract.read();
+        require(pending.is_some(), ProtocolError::NoPendingFeeContract);
+        let pending = pending.unwrap();
+        require(timestamp() >= pending.effective_at, ProtocolError::FeeContractChangeTimelocked);
+
+        storage.pending_fee_contract.write(None);
+        storage.fee_contract.write(pending.fee_contract);
+
+        log(FeeContractSetEvent { fee_contract: pending.fee_contract });
+    }
+
+    /// Sets the affiliate fee percentage used by contracts that do not configure their own.
+    ///
+    /// # Arguments
+    ///
+    /// * `percentage`: [u64] - The affiliate share of the mint price, from 0 to 100.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the registry owner.
+    /// * When `percentage` is greater than 100.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.set_default_affiliate_fee_percentage(10);
+    ///     assert(registry_abi.protocol_config().affiliate_fee_percentage == 10);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_default_affiliate_fee_percentage(percentage: u64) {
+        only_owner();
+        require(percentage <= 100, ProtocolError::InvalidPercentage);
+        storage.default_affiliate_fee_percentage.write(percentage);
+
+        log(DefaultAffiliateFeeSetEvent { percentage });
+    }
+
+    /// Pauses or resumes minting across all contracts reading from this registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `paused`: [bool] - Whether minting should be paused.
+    ///
+    /// # Reverts
+    ///
+    /// * When the caller is not the registry owner.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    /// * Writes: `1`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     registry_abi.set_protocol_paused(true);
+    ///     assert(registry_abi.protocol_config().paused);
+    /// }
+    /// ```
+    #[storage(read, write)]
+    fn set_protocol_paused(paused: bool) {
+        only_owner();
+        storage.protocol_paused.write(paused);
+
+        log(ProtocolPausedEvent { paused });
+    }
+
+    /// Returns the protocol-wide configuration read by Props contracts at mint time.
+    ///
+    /// # Returns
+    ///
+    /// * [ProtocolConfig] - The fee contract, default affiliate fee percentage and pause flag.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `3`
+    ///
+    /// # Examples
+    ///
+    /// ```sway
+    /// use libraries::PropsRegistry;
+    ///
+    /// fn foo(registry_id: ContractId) {
+    ///     let registry_abi = abi(PropsRegistry, registry_id.bits());
+    ///     assert(!registry_abi.protocol_config().paused);
+    /// }
+    /// ```
+    #[storage(read)]
+    fn protocol_config() -> ProtocolConfig {
+        ProtocolConfig {
+            fee_contract: storage.fee_contract.read(),
+            affiliate_fee_percentage: storage.default_affiliate_fee_percentage.read(),
+            paused: storage.protocol_paused.read(),
+        }
+    }
 }
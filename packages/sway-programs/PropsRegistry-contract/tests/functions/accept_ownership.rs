@@ -0,0 +1,81 @@
+use crate::utils::{
+    interface::{accept_ownership, constructor, owner, pending_owner, transfer_ownership},
+    setup::{defaults, setup, State},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn accepts_ownership() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        transfer_ownership(&instance_1, other_identity).await;
+
+        let response = accept_ownership(&instance_2).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("OwnershipTransferred")));
+
+        assert_eq!(owner(&instance_1).await, State::Initialized(other_identity));
+        assert_eq!(pending_owner(&instance_1).await, None);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_not_pending_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        transfer_ownership(&instance_1, owner_identity).await;
+
+        accept_ownership(&instance_2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_no_transfer_pending() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        accept_ownership(&instance_2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_previous_owner_calls_transfer_after_accept() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        transfer_ownership(&instance_1, other_identity).await;
+        accept_ownership(&instance_2).await;
+
+        transfer_ownership(&instance_1, owner_identity).await;
+    }
+}
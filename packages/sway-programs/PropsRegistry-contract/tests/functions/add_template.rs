@@ -0,0 +1,41 @@
+use crate::utils::{
+    interface::{add_template, constructor, is_template},
+    setup::{defaults, setup, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn adds_template() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        assert!(!is_template(&instance_1, template_root()).await);
+
+        let response = add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("TemplateAddedEvent")));
+
+        assert!(is_template(&instance_1, template_root()).await);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        add_template(&instance_2, template_root(), ContractType::Collection).await;
+    }
+}
@@ -0,0 +1,94 @@
+use crate::utils::{
+    interface::{
+        apply_fee_contract, constructor, pending_fee_contract, protocol_config, set_fee_contract,
+        skip_fee_contract_delay,
+    },
+    setup::{defaults, deploy_target, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn applies_fee_contract_after_delay() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let fee_contract = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        set_fee_contract(&instance_1, Some(fee_contract)).await;
+        skip_fee_contract_delay(&instance_1).await;
+
+        let response = apply_fee_contract(&instance_1).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("FeeContractSetEvent")));
+        assert_eq!(protocol_config(&instance_1).await.fee_contract, Some(fee_contract));
+        assert_eq!(pending_fee_contract(&instance_1).await, None);
+    }
+
+    #[tokio::test]
+    async fn anyone_can_apply() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let fee_contract = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        set_fee_contract(&instance_1, Some(fee_contract)).await;
+        skip_fee_contract_delay(&instance_1).await;
+
+        apply_fee_contract(&instance_2).await;
+        assert_eq!(protocol_config(&instance_1).await.fee_contract, Some(fee_contract));
+    }
+
+    #[tokio::test]
+    async fn clears_fee_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let fee_contract = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        set_fee_contract(&instance_1, Some(fee_contract)).await;
+        skip_fee_contract_delay(&instance_1).await;
+        apply_fee_contract(&instance_1).await;
+
+        set_fee_contract(&instance_1, None).await;
+        skip_fee_contract_delay(&instance_1).await;
+        apply_fee_contract(&instance_1).await;
+
+        assert_eq!(protocol_config(&instance_1).await.fee_contract, None);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NoPendingFeeContract")]
+    async fn when_nothing_is_queued() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        apply_fee_contract(&instance_1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "FeeContractChangeTimelocked")]
+    async fn when_delay_has_not_passed() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let fee_contract = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        set_fee_contract(&instance_1, Some(fee_contract)).await;
+
+        apply_fee_contract(&instance_1).await;
+    }
+}
@@ -0,0 +1,27 @@
+use crate::utils::{
+    interface::{add_template, constructor, contract_type, deregister, register},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_template_type() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Edition).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        assert_eq!(contract_type(&instance_1, contract_id).await, None);
+
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        assert_eq!(contract_type(&instance_1, contract_id).await, Some(ContractType::Edition));
+
+        deregister(&instance_1, contract_id).await;
+        assert_eq!(contract_type(&instance_1, contract_id).await, None);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::utils::{
+    interface::{add_template, constructor, contracts, deregister, register},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_paginated_contracts() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        let contract_id_1 = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let contract_id_2 = deploy_target(&owner_wallet, owner_identity, 2).await;
+        let contract_id_3 = deploy_target(&owner_wallet, owner_identity, 3).await;
+        register(&instance_1, contract_id_1, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, contract_id_2, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, contract_id_3, owner_identity, template_bytecode(), None).await;
+
+        assert_eq!(contracts(&instance_1, 0, 10).await, vec![contract_id_1, contract_id_2, contract_id_3]);
+        assert_eq!(contracts(&instance_1, 1, 1).await, vec![contract_id_2]);
+        assert_eq!(contracts(&instance_1, 3, 10).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn keeps_index_consistent_on_deregister() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        let contract_id_1 = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let contract_id_2 = deploy_target(&owner_wallet, owner_identity, 2).await;
+        let contract_id_3 = deploy_target(&owner_wallet, owner_identity, 3).await;
+        register(&instance_1, contract_id_1, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, contract_id_2, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, contract_id_3, owner_identity, template_bytecode(), None).await;
+
+        deregister(&instance_1, contract_id_1).await;
+        assert_eq!(contracts(&instance_1, 0, 10).await, vec![contract_id_3, contract_id_2]);
+
+        // The moved contract can still be removed from its new position
+        deregister(&instance_1, contract_id_3).await;
+        assert_eq!(contracts(&instance_1, 0, 10).await, vec![contract_id_2]);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::utils::{
+    interface::{accept_ownership, add_template, constructor, contracts_by_owner, deregister, register, transfer_ownership},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, PropsRegistry, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_contracts_of_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        let contract_id_1 = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let contract_id_2 = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, contract_id_1, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, contract_id_2, owner_identity, template_bytecode(), None).await;
+
+        assert_eq!(contracts_by_owner(&instance_1, owner_identity, 0, 10).await, vec![contract_id_1, contract_id_2]);
+        assert_eq!(contracts_by_owner(&instance_1, owner_identity, 1, 10).await, vec![contract_id_2]);
+        assert_eq!(contracts_by_owner(&instance_1, other_identity, 0, 10).await, vec![]);
+
+        deregister(&instance_1, contract_id_1).await;
+        assert_eq!(contracts_by_owner(&instance_1, owner_identity, 0, 10).await, vec![contract_id_2]);
+    }
+
+    #[tokio::test]
+    async fn moves_contract_to_new_owner_on_re_registration() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        // Hand the registered contract over to the other wallet
+        transfer_ownership(&PropsRegistry::new(contract_id, owner_wallet.clone()), other_identity).await;
+        accept_ownership(&PropsRegistry::new(contract_id, other_wallet.clone())).await;
+
+        register(&instance_2, contract_id, other_identity, template_bytecode(), None).await;
+
+        assert_eq!(contracts_by_owner(&instance_1, owner_identity, 0, 10).await, vec![]);
+        assert_eq!(contracts_by_owner(&instance_1, other_identity, 0, 10).await, vec![contract_id]);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::utils::{
+    interface::{add_template, constructor, count, deregister, register},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_registered_contracts() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        assert_eq!(count(&instance_1).await, 0);
+
+        let contract_id_1 = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let contract_id_2 = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, contract_id_1, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, contract_id_2, owner_identity, template_bytecode(), None).await;
+        assert_eq!(count(&instance_1).await, 2);
+
+        // Re-registering does not add a second entry
+        register(&instance_1, contract_id_1, owner_identity, template_bytecode(), None).await;
+        assert_eq!(count(&instance_1).await, 2);
+
+        deregister(&instance_1, contract_id_1).await;
+        assert_eq!(count(&instance_1).await, 1);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::utils::{
+    interface::{accept_ownership, add_template, constructor, deprecate, is_deprecated, register, transfer_ownership},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType, PropsRegistry},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn deprecates_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        assert!(!is_deprecated(&instance_1, contract_id).await);
+
+        let response = deprecate(&instance_1, contract_id).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("ContractDeprecatedEvent")));
+
+        assert!(is_deprecated(&instance_1, contract_id).await);
+    }
+
+    #[tokio::test]
+    async fn deprecates_contract_after_ownership_transfer() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        transfer_ownership(&PropsRegistry::new(contract_id, owner_wallet.clone()), other_identity).await;
+        accept_ownership(&PropsRegistry::new(contract_id, other_wallet.clone())).await;
+
+        deprecate(&instance_2, contract_id).await;
+
+        assert!(is_deprecated(&instance_1, contract_id).await);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotContractOwner")]
+    async fn when_not_contract_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        deprecate(&instance_2, contract_id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotContractOwner")]
+    async fn when_ownership_was_transferred_away() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        transfer_ownership(&PropsRegistry::new(contract_id, owner_wallet.clone()), other_identity).await;
+        accept_ownership(&PropsRegistry::new(contract_id, other_wallet.clone())).await;
+
+        deprecate(&instance_1, contract_id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRegistered")]
+    async fn when_contract_not_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        deprecate(&instance_1, contract_id).await;
+    }
+}
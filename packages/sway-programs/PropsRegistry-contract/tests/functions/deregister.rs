@@ -1,10 +1,6 @@
 use crate::utils::{
-    interface::{constructor, register, deregister, owner},
-    setup::{defaults, setup, State},
-};
-
-use fuels::{
-    types::{Address, ContractId, Identity},
+    interface::{add_template, constructor, register, deregister, owner},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, State, ContractType},
 };
 
 mod success {
@@ -18,13 +14,12 @@ mod success {
 
         constructor(&instance_1, owner_identity).await;
 
-        let contract_id = ContractId::from(*id);
-        let owner_address = Identity::Address(Address::from(owner_wallet.clone().address()));
-
         assert_eq!(owner(&instance_1).await, State::Initialized(owner_identity));
 
         // First, register the contract
-        register(&instance_1, contract_id, owner_address).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
 
         // Now, deregister the contract using the owner's wallet
         let response = deregister(&instance_1, contract_id).await;
@@ -53,11 +48,10 @@ mod revert {
 
         constructor(&instance_1, owner_identity).await;
 
-        let contract_id = ContractId::from(*id);
-        let owner_address = Identity::Address(Address::from(owner_wallet.clone().address()));
-
         // First, register the contract
-        register(&instance_1, contract_id, owner_address).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
 
         // Try to deregister the contract with the other wallet (not owner)
         deregister(&instance_2, contract_id).await;
@@ -0,0 +1,161 @@
+use crate::utils::{
+    interface::{accept_ownership, add_template, constructor, is_deprecated, migrate, migrated_to, register, transfer_ownership},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType, PropsRegistry},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn migrates_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let old_contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let new_contract_id = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, old_contract_id, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, new_contract_id, owner_identity, template_bytecode(), None).await;
+
+        assert_eq!(migrated_to(&instance_1, old_contract_id).await, None);
+
+        let response = migrate(&instance_1, old_contract_id, new_contract_id).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("ContractMigratedEvent")));
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("ContractDeprecatedEvent")));
+
+        assert_eq!(migrated_to(&instance_1, old_contract_id).await, Some(new_contract_id));
+        assert!(is_deprecated(&instance_1, old_contract_id).await);
+        assert!(!is_deprecated(&instance_1, new_contract_id).await);
+    }
+
+    #[tokio::test]
+    async fn builds_migration_chain() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let old_contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let new_contract_id = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, old_contract_id, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, new_contract_id, owner_identity, template_bytecode(), None).await;
+        let latest_contract_id = deploy_target(&owner_wallet, owner_identity, 3).await;
+        register(&instance_1, latest_contract_id, owner_identity, template_bytecode(), None).await;
+
+        migrate(&instance_1, old_contract_id, new_contract_id).await;
+        migrate(&instance_1, new_contract_id, latest_contract_id).await;
+
+        assert_eq!(migrated_to(&instance_1, old_contract_id).await, Some(new_contract_id));
+        assert_eq!(migrated_to(&instance_1, new_contract_id).await, Some(latest_contract_id));
+        assert_eq!(migrated_to(&instance_1, latest_contract_id).await, None);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotContractOwner")]
+    async fn when_not_contract_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let old_contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let new_contract_id = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, old_contract_id, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, new_contract_id, owner_identity, template_bytecode(), None).await;
+
+        migrate(&instance_2, old_contract_id, new_contract_id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotContractOwner")]
+    async fn when_new_contract_ownership_was_transferred_away() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let old_contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let new_contract_id = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, old_contract_id, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, new_contract_id, owner_identity, template_bytecode(), None).await;
+
+        transfer_ownership(&PropsRegistry::new(new_contract_id, owner_wallet.clone()), other_identity).await;
+        accept_ownership(&PropsRegistry::new(new_contract_id, other_wallet.clone())).await;
+
+        migrate(&instance_1, old_contract_id, new_contract_id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRegistered")]
+    async fn when_new_contract_not_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let old_contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let new_contract_id = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, old_contract_id, owner_identity, template_bytecode(), None).await;
+
+        migrate(&instance_1, old_contract_id, new_contract_id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyMigrated")]
+    async fn when_already_migrated() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let old_contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let new_contract_id = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, old_contract_id, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, new_contract_id, owner_identity, template_bytecode(), None).await;
+        migrate(&instance_1, old_contract_id, new_contract_id).await;
+
+        migrate(&instance_1, old_contract_id, new_contract_id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidMigration")]
+    async fn when_migrating_to_itself() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let old_contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let new_contract_id = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, old_contract_id, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, new_contract_id, owner_identity, template_bytecode(), None).await;
+
+        migrate(&instance_1, new_contract_id, new_contract_id).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidMigration")]
+    async fn when_new_contract_was_migrated() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let old_contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        let new_contract_id = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, old_contract_id, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, new_contract_id, owner_identity, template_bytecode(), None).await;
+        migrate(&instance_1, new_contract_id, old_contract_id).await;
+
+        // Pointing old back to new would close a loop
+        migrate(&instance_1, old_contract_id, new_contract_id).await;
+    }
+}
@@ -1,3 +1,24 @@
 mod constructor;
 mod register;
-mod deregister;
\ No newline at end of file
+mod deregister;
+mod transfer_ownership;
+mod accept_ownership;
+mod renounce_ownership;
+mod add_template;
+mod remove_template;
+mod is_registered;
+mod owner_of;
+mod contracts;
+mod contracts_by_owner;
+mod count;
+mod contract_type;
+mod set_curator;
+mod set_verified;
+mod set_category;
+mod set_tags;
+mod migrate;
+mod deprecate;
+mod set_fee_contract;
+mod apply_fee_contract;
+mod set_default_affiliate_fee_percentage;
+mod set_protocol_paused;
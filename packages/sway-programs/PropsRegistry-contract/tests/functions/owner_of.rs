@@ -0,0 +1,24 @@
+use crate::utils::{
+    interface::{add_template, constructor, owner_of, register},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_registered_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        assert_eq!(owner_of(&instance_1, contract_id).await, None);
+
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        assert_eq!(owner_of(&instance_1, contract_id).await, Some(owner_identity));
+    }
+}
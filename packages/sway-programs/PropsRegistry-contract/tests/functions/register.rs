@@ -1,10 +1,6 @@
 use crate::utils::{
-    interface::{constructor, register},
-    setup::{defaults, setup},
-};
-
-use fuels::{
-    types::{Address, ContractId,Identity},
+    interface::{add_template, add_template_with_configurables, add_template_with_registry_offset, constructor, register},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
 };
 
 mod success {
@@ -20,11 +16,11 @@ mod success {
         ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
 
         constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
 
-        let contract_id = ContractId::from(*id);
-        let owner_address = Identity::Address(Address::from(owner_wallet.clone().address()));
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
 
-        let response = register(&instance_1, contract_id, owner_address).await;
+        let response = register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
         let logs = response.decode_logs();
         // Check if a RegisterEvent is present in the logs
         assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("RegisterEvent")));
@@ -40,4 +36,155 @@ mod success {
         // Check if the log contains the correct owner address (in hex format without leading "0x")
         assert!(log_content.contains(&owner_address_hex), "Log does not contain the correct owner address");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn allows_contract_owner_to_re_register() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        let response = register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("RegisterEvent")));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "UnknownBytecode")]
+    async fn when_template_not_allowlisted() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "RegistryMismatch")]
+    async fn when_registry_contract_id_is_not_this_registry() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        // The first 32 bytes of the template are code, so they can never hold this registry's id
+        add_template_with_registry_offset(&instance_1, template_root(), ContractType::Collection, 0).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "BytecodeMismatch")]
+    async fn when_configurables_do_not_match_deployment() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template_with_configurables(&instance_1, template_root(), ContractType::Collection, vec![(0, 8)]).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), Some(vec![(0, vec![1u8; 8])])).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ConfigurableOutOfRange")]
+    async fn when_configurables_patch_outside_configurable_range() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template_with_configurables(&instance_1, template_root(), ContractType::Collection, vec![(0, 8)]).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), Some(vec![(4, vec![1u8; 8])])).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OverlappingConfigurables")]
+    async fn when_configurables_overlap() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template_with_configurables(&instance_1, template_root(), ContractType::Collection, vec![(0, 16)]).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        register(
+            &instance_1,
+            contract_id,
+            owner_identity,
+            template_bytecode(),
+            Some(vec![(0, vec![1u8; 8]), (4, vec![2u8; 8])]),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "OwnerMismatch")]
+    async fn when_owner_is_not_contract_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        register(&instance_2, contract_id, other_identity, template_bytecode(), None).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotContractOwner")]
+    async fn when_re_registered_by_non_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            _other_identity,
+        ) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        register(&instance_2, contract_id, owner_identity, template_bytecode(), None).await;
+    }
+}
@@ -0,0 +1,63 @@
+use crate::utils::{
+    interface::{constructor, owner, pending_owner, renounce_ownership, transfer_ownership},
+    setup::{defaults, setup, State},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn renounces_ownership() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        transfer_ownership(&instance_1, other_identity).await;
+
+        let response = renounce_ownership(&instance_1).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("OwnershipRenounced")));
+
+        assert_eq!(owner(&instance_1).await, State::Revoked);
+        assert_eq!(pending_owner(&instance_1).await, None);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        renounce_ownership(&instance_2).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_already_renounced() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (
+            owner_identity,
+            other_identity,
+        ) = defaults(id, owner_wallet, other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        renounce_ownership(&instance_1).await;
+        renounce_ownership(&instance_1).await;
+    }
+}
@@ -0,0 +1,100 @@
+use crate::utils::{
+    interface::{add_template, category, constructor, contracts_by_category, deregister, register, set_category},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_category() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        let response = set_category(&instance_1, contract_id, Some(String::from("art"))).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("CategorySetEvent")));
+
+        assert_eq!(category(&instance_1, contract_id).await, Some(String::from("art")));
+        assert_eq!(contracts_by_category(&instance_1, String::from("art"), 0, 10).await, vec![contract_id]);
+    }
+
+    #[tokio::test]
+    async fn moves_contract_between_categories() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        let contract_id_2 = deploy_target(&owner_wallet, owner_identity, 2).await;
+        register(&instance_1, contract_id_2, owner_identity, template_bytecode(), None).await;
+
+        set_category(&instance_1, contract_id, Some(String::from("art"))).await;
+        set_category(&instance_1, contract_id_2, Some(String::from("art"))).await;
+        set_category(&instance_1, contract_id, Some(String::from("music"))).await;
+
+        assert_eq!(contracts_by_category(&instance_1, String::from("art"), 0, 10).await, vec![contract_id_2]);
+        assert_eq!(contracts_by_category(&instance_1, String::from("music"), 0, 10).await, vec![contract_id]);
+    }
+
+    #[tokio::test]
+    async fn clears_category() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        set_category(&instance_1, contract_id, Some(String::from("art"))).await;
+
+        set_category(&instance_1, contract_id, None).await;
+
+        assert_eq!(category(&instance_1, contract_id).await, None);
+        assert_eq!(contracts_by_category(&instance_1, String::from("art"), 0, 10).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn clears_category_on_deregister() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        set_category(&instance_1, contract_id, Some(String::from("art"))).await;
+
+        deregister(&instance_1, contract_id).await;
+
+        assert_eq!(category(&instance_1, contract_id).await, None);
+        assert_eq!(contracts_by_category(&instance_1, String::from("art"), 0, 10).await, vec![]);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotCurator")]
+    async fn when_not_curator() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        set_category(&instance_2, contract_id, Some(String::from("art"))).await;
+    }
+}
@@ -0,0 +1,73 @@
+use crate::utils::{
+    interface::{add_template, constructor, is_curator, is_verified, register, set_curator, set_verified},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn grants_and_revokes_curator() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        let response = set_curator(&instance_1, other_identity, true).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("CuratorSetEvent")));
+        assert!(is_curator(&instance_1, other_identity).await);
+
+        set_curator(&instance_1, other_identity, false).await;
+        assert!(!is_curator(&instance_1, other_identity).await);
+    }
+
+    #[tokio::test]
+    async fn curator_can_curate() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        set_curator(&instance_1, other_identity, true).await;
+
+        set_verified(&instance_2, contract_id, true).await;
+
+        assert!(is_verified(&instance_1, contract_id).await);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_curator(&instance_2, other_identity, true).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotCurator")]
+    async fn when_curator_was_revoked() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        set_curator(&instance_1, other_identity, true).await;
+        set_curator(&instance_1, other_identity, false).await;
+
+        set_verified(&instance_2, contract_id, true).await;
+    }
+}
@@ -0,0 +1,50 @@
+use crate::utils::{
+    interface::{constructor, protocol_config, set_default_affiliate_fee_percentage},
+    setup::{defaults, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_default_affiliate_fee_percentage() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        assert_eq!(protocol_config(&instance_1).await.affiliate_fee_percentage, 0);
+
+        let response = set_default_affiliate_fee_percentage(&instance_1, 10).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("DefaultAffiliateFeeSetEvent")));
+        assert_eq!(protocol_config(&instance_1).await.affiliate_fee_percentage, 10);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_default_affiliate_fee_percentage(&instance_2, 10).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidPercentage")]
+    async fn when_percentage_exceeds_100() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_default_affiliate_fee_percentage(&instance_1, 101).await;
+    }
+}
@@ -0,0 +1,67 @@
+use crate::utils::{
+    interface::{constructor, pending_fee_contract, protocol_config, set_fee_contract},
+    setup::{defaults, deploy_target, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn defaults_to_none() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        assert_eq!(protocol_config(&instance_1).await.fee_contract, None);
+        assert_eq!(pending_fee_contract(&instance_1).await, None);
+    }
+
+    #[tokio::test]
+    async fn queues_fee_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let fee_contract = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        let response = set_fee_contract(&instance_1, Some(fee_contract)).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("FeeContractChangeQueuedEvent")));
+
+        // The change does not apply until the delay has passed
+        assert_eq!(protocol_config(&instance_1).await.fee_contract, None);
+        assert_eq!(pending_fee_contract(&instance_1).await.unwrap().fee_contract, Some(fee_contract));
+    }
+
+    #[tokio::test]
+    async fn replaces_queued_change() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let fee_contract = deploy_target(&owner_wallet, owner_identity, 1).await;
+
+        set_fee_contract(&instance_1, Some(fee_contract)).await;
+        set_fee_contract(&instance_1, None).await;
+
+        assert_eq!(pending_fee_contract(&instance_1).await.unwrap().fee_contract, None);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_fee_contract(&instance_2, Some(id)).await;
+    }
+}
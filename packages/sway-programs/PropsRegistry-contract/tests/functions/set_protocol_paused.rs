@@ -0,0 +1,42 @@
+use crate::utils::{
+    interface::{constructor, protocol_config, set_protocol_paused},
+    setup::{defaults, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pauses_and_resumes_protocol() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        assert!(!protocol_config(&instance_1).await.paused);
+
+        let response = set_protocol_paused(&instance_1, true).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("ProtocolPausedEvent")));
+        assert!(protocol_config(&instance_1).await.paused);
+
+        set_protocol_paused(&instance_1, false).await;
+        assert!(!protocol_config(&instance_1).await.paused);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+
+        set_protocol_paused(&instance_2, true).await;
+    }
+}
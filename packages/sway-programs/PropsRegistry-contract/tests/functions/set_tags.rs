@@ -0,0 +1,63 @@
+use crate::utils::{
+    interface::{add_template, constructor, register, set_tags, tags},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_tags() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        let response = set_tags(&instance_1, contract_id, vec![String::from("pixel"), String::from("generative")]).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("TagsSetEvent")));
+
+        assert_eq!(tags(&instance_1, contract_id).await, vec![String::from("pixel"), String::from("generative")]);
+    }
+
+    #[tokio::test]
+    async fn replaces_tags() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        set_tags(&instance_1, contract_id, vec![String::from("pixel"), String::from("generative")]).await;
+
+        set_tags(&instance_1, contract_id, vec![String::from("photography")]).await;
+        assert_eq!(tags(&instance_1, contract_id).await, vec![String::from("photography")]);
+
+        set_tags(&instance_1, contract_id, vec![]).await;
+        assert_eq!(tags(&instance_1, contract_id).await, Vec::<String>::new());
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotCurator")]
+    async fn when_not_curator() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        set_tags(&instance_2, contract_id, vec![String::from("pixel")]).await;
+    }
+}
@@ -0,0 +1,98 @@
+use crate::utils::{
+    interface::{add_template, constructor, deregister, is_verified, register, set_verified, verified_contracts},
+    setup::{defaults, deploy_target, setup, template_bytecode, template_root, ContractType},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn verifies_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        let response = set_verified(&instance_1, contract_id, true).await;
+        let logs = response.decode_logs();
+        assert!(logs.results.iter().any(|log| log.as_ref().unwrap().contains("VerifiedSetEvent")));
+
+        assert!(is_verified(&instance_1, contract_id).await);
+        assert_eq!(verified_contracts(&instance_1, 0, 10).await, vec![contract_id]);
+    }
+
+    #[tokio::test]
+    async fn filters_verified_contracts() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        let contract_id_2 = deploy_target(&owner_wallet, owner_identity, 2).await;
+        let contract_id_3 = deploy_target(&owner_wallet, owner_identity, 3).await;
+        register(&instance_1, contract_id_2, owner_identity, template_bytecode(), None).await;
+        register(&instance_1, contract_id_3, owner_identity, template_bytecode(), None).await;
+
+        set_verified(&instance_1, contract_id, true).await;
+        set_verified(&instance_1, contract_id_3, true).await;
+        assert_eq!(verified_contracts(&instance_1, 0, 10).await, vec![contract_id, contract_id_3]);
+
+        set_verified(&instance_1, contract_id, false).await;
+        assert!(!is_verified(&instance_1, contract_id).await);
+        assert_eq!(verified_contracts(&instance_1, 0, 10).await, vec![contract_id_3]);
+    }
+
+    #[tokio::test]
+    async fn clears_verification_on_deregister() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+        set_verified(&instance_1, contract_id, true).await;
+
+        deregister(&instance_1, contract_id).await;
+
+        assert!(!is_verified(&instance_1, contract_id).await);
+        assert_eq!(verified_contracts(&instance_1, 0, 10).await, vec![]);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotCurator")]
+    async fn when_not_curator() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        add_template(&instance_1, template_root(), ContractType::Collection).await;
+        let contract_id = deploy_target(&owner_wallet, owner_identity, 1).await;
+        register(&instance_1, contract_id, owner_identity, template_bytecode(), None).await;
+
+        set_verified(&instance_2, contract_id, true).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRegistered")]
+    async fn when_contract_not_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (owner_identity, _other_identity) = defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        constructor(&instance_1, owner_identity).await;
+        let unregistered = deploy_target(&owner_wallet, owner_identity, 2).await;
+
+        set_verified(&instance_1, unregistered, true).await;
+    }
+}
@@ -1,18 +1,23 @@
-use crate::utils::setup::{PropsRegistry, State};
+use crate::utils::setup::{ContractType, PendingFeeContract, PropsRegistry, ProtocolConfig, State};
+use chrono::{Duration, Utc};
 use fuels::{
-    prelude::{WalletUnlocked, ContractId},
+    accounts::ViewOnlyAccount,
+    prelude::{WalletUnlocked, ContractId, Bech32ContractId},
     programs::{call_response::FuelCallResponse},
-    types::{Identity},
+    types::{Bits256, Identity},
 };
 
 pub(crate) async fn register(
     contract: &PropsRegistry<WalletUnlocked>,
     contract_id: ContractId,
     owner: Identity,
+    bytecode: Vec<u8>,
+    configurables: Option<Vec<(u64, Vec<u8>)>>,
 ) -> FuelCallResponse<()> {
     contract
         .methods()
-        .register(contract_id, owner)
+        .register(contract_id, owner, bytecode, configurables)
+        .with_contract_ids(&[Bech32ContractId::from(contract_id)])
         .call()
         .await
         .unwrap()
@@ -30,6 +35,39 @@ pub(crate) async fn deregister(
         .unwrap()
 }
 
+pub(crate) async fn is_registered(contract: &PropsRegistry<WalletUnlocked>, contract_id: ContractId) -> bool {
+    contract.methods().is_registered(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn owner_of(contract: &PropsRegistry<WalletUnlocked>, contract_id: ContractId) -> Option<Identity> {
+    contract.methods().owner_of(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn contract_type(contract: &PropsRegistry<WalletUnlocked>, contract_id: ContractId) -> Option<ContractType> {
+    contract.methods().contract_type(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn contracts(
+    contract: &PropsRegistry<WalletUnlocked>,
+    offset: u64,
+    limit: u64,
+) -> Vec<ContractId> {
+    contract.methods().contracts(offset, limit).call().await.unwrap().value
+}
+
+pub(crate) async fn contracts_by_owner(
+    contract: &PropsRegistry<WalletUnlocked>,
+    owner: Identity,
+    offset: u64,
+    limit: u64,
+) -> Vec<ContractId> {
+    contract.methods().contracts_by_owner(owner, offset, limit).call().await.unwrap().value
+}
+
+pub(crate) async fn count(contract: &PropsRegistry<WalletUnlocked>) -> u64 {
+    contract.methods().count().call().await.unwrap().value
+}
+
 pub(crate) async fn constructor(
     contract: &PropsRegistry<WalletUnlocked>,
     owner: Identity,
@@ -41,4 +79,192 @@ pub(crate) async fn owner(contract: &PropsRegistry<WalletUnlocked>) -> State {
     contract.methods().owner().call().await.unwrap().value
 }
 
+pub(crate) async fn add_template(
+    contract: &PropsRegistry<WalletUnlocked>,
+    bytecode_root: Bits256,
+    contract_type: ContractType,
+) -> FuelCallResponse<()> {
+    add_template_with_configurables(contract, bytecode_root, contract_type, vec![]).await
+}
+
+pub(crate) async fn add_template_with_registry_offset(
+    contract: &PropsRegistry<WalletUnlocked>,
+    bytecode_root: Bits256,
+    contract_type: ContractType,
+    registry_offset: u64,
+) -> FuelCallResponse<()> {
+    contract
+        .methods()
+        .add_template(bytecode_root, contract_type, vec![], Some(registry_offset))
+        .call()
+        .await
+        .unwrap()
+}
 
+pub(crate) async fn add_template_with_configurables(
+    contract: &PropsRegistry<WalletUnlocked>,
+    bytecode_root: Bits256,
+    contract_type: ContractType,
+    configurable_ranges: Vec<(u64, u64)>,
+) -> FuelCallResponse<()> {
+    contract
+        .methods()
+        .add_template(bytecode_root, contract_type, configurable_ranges, None)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn remove_template(
+    contract: &PropsRegistry<WalletUnlocked>,
+    bytecode_root: Bits256,
+) -> FuelCallResponse<()> {
+    contract.methods().remove_template(bytecode_root).call().await.unwrap()
+}
+
+pub(crate) async fn is_template(contract: &PropsRegistry<WalletUnlocked>, bytecode_root: Bits256) -> bool {
+    contract.methods().is_template(bytecode_root).call().await.unwrap().value
+}
+
+pub(crate) async fn transfer_ownership(
+    contract: &PropsRegistry<WalletUnlocked>,
+    new_owner: Identity,
+) -> FuelCallResponse<()> {
+    contract.methods().transfer_ownership(new_owner).call().await.unwrap()
+}
+
+pub(crate) async fn accept_ownership(contract: &PropsRegistry<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().accept_ownership().call().await.unwrap()
+}
+
+pub(crate) async fn renounce_ownership(contract: &PropsRegistry<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().renounce_ownership().call().await.unwrap()
+}
+
+pub(crate) async fn pending_owner(contract: &PropsRegistry<WalletUnlocked>) -> Option<Identity> {
+    contract.methods().pending_owner().call().await.unwrap().value
+}
+
+pub(crate) async fn set_curator(
+    contract: &PropsRegistry<WalletUnlocked>,
+    curator: Identity,
+    enabled: bool,
+) -> FuelCallResponse<()> {
+    contract.methods().set_curator(curator, enabled).call().await.unwrap()
+}
+
+pub(crate) async fn is_curator(contract: &PropsRegistry<WalletUnlocked>, identity: Identity) -> bool {
+    contract.methods().is_curator(identity).call().await.unwrap().value
+}
+
+pub(crate) async fn set_verified(
+    contract: &PropsRegistry<WalletUnlocked>,
+    contract_id: ContractId,
+    verified: bool,
+) -> FuelCallResponse<()> {
+    contract.methods().set_verified(contract_id, verified).call().await.unwrap()
+}
+
+pub(crate) async fn is_verified(contract: &PropsRegistry<WalletUnlocked>, contract_id: ContractId) -> bool {
+    contract.methods().is_verified(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn verified_contracts(
+    contract: &PropsRegistry<WalletUnlocked>,
+    offset: u64,
+    limit: u64,
+) -> Vec<ContractId> {
+    contract.methods().verified_contracts(offset, limit).call().await.unwrap().value
+}
+
+pub(crate) async fn set_category(
+    contract: &PropsRegistry<WalletUnlocked>,
+    contract_id: ContractId,
+    category: Option<String>,
+) -> FuelCallResponse<()> {
+    contract.methods().set_category(contract_id, category).call().await.unwrap()
+}
+
+pub(crate) async fn category(contract: &PropsRegistry<WalletUnlocked>, contract_id: ContractId) -> Option<String> {
+    contract.methods().category(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn contracts_by_category(
+    contract: &PropsRegistry<WalletUnlocked>,
+    category: String,
+    offset: u64,
+    limit: u64,
+) -> Vec<ContractId> {
+    contract.methods().contracts_by_category(category, offset, limit).call().await.unwrap().value
+}
+
+pub(crate) async fn set_tags(
+    contract: &PropsRegistry<WalletUnlocked>,
+    contract_id: ContractId,
+    tags: Vec<String>,
+) -> FuelCallResponse<()> {
+    contract.methods().set_tags(contract_id, tags).call().await.unwrap()
+}
+
+pub(crate) async fn tags(contract: &PropsRegistry<WalletUnlocked>, contract_id: ContractId) -> Vec<String> {
+    contract.methods().tags(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn migrate(
+    contract: &PropsRegistry<WalletUnlocked>,
+    old_contract_id: ContractId,
+    new_contract_id: ContractId,
+) -> FuelCallResponse<()> {
+    contract.methods().migrate(old_contract_id, new_contract_id).call().await.unwrap()
+}
+
+pub(crate) async fn migrated_to(contract: &PropsRegistry<WalletUnlocked>, old_contract_id: ContractId) -> Option<ContractId> {
+    contract.methods().migrated_to(old_contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn deprecate(contract: &PropsRegistry<WalletUnlocked>, contract_id: ContractId) -> FuelCallResponse<()> {
+    contract.methods().deprecate(contract_id).call().await.unwrap()
+}
+
+pub(crate) async fn is_deprecated(contract: &PropsRegistry<WalletUnlocked>, contract_id: ContractId) -> bool {
+    contract.methods().is_deprecated(contract_id).call().await.unwrap().value
+}
+
+pub(crate) async fn set_fee_contract(
+    contract: &PropsRegistry<WalletUnlocked>,
+    fee_contract: Option<ContractId>,
+) -> FuelCallResponse<()> {
+    contract.methods().set_fee_contract(fee_contract).call().await.unwrap()
+}
+
+pub(crate) async fn pending_fee_contract(contract: &PropsRegistry<WalletUnlocked>) -> Option<PendingFeeContract> {
+    contract.methods().pending_fee_contract().call().await.unwrap().value
+}
+
+pub(crate) async fn apply_fee_contract(contract: &PropsRegistry<WalletUnlocked>) -> FuelCallResponse<()> {
+    contract.methods().apply_fee_contract().call().await.unwrap()
+}
+
+/// Moves the chain past the fee contract change delay.
+pub(crate) async fn skip_fee_contract_delay(contract: &PropsRegistry<WalletUnlocked>) {
+    let provider = contract.account().try_provider().unwrap().clone();
+    provider
+        .produce_blocks(1, Some(Utc::now() + Duration::days(8)))
+        .await
+        .unwrap();
+}
+
+pub(crate) async fn set_default_affiliate_fee_percentage(
+    contract: &PropsRegistry<WalletUnlocked>,
+    percentage: u64,
+) -> FuelCallResponse<()> {
+    contract.methods().set_default_affiliate_fee_percentage(percentage).call().await.unwrap()
+}
+
+pub(crate) async fn set_protocol_paused(contract: &PropsRegistry<WalletUnlocked>, paused: bool) -> FuelCallResponse<()> {
+    contract.methods().set_protocol_paused(paused).call().await.unwrap()
+}
+
+pub(crate) async fn protocol_config(contract: &PropsRegistry<WalletUnlocked>) -> ProtocolConfig {
+    contract.methods().protocol_config().call().await.unwrap().value
+}
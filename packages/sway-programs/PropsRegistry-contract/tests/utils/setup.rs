@@ -1,6 +1,6 @@
 use fuels::{
     prelude::*,
-    types::{Address, AssetId, Identity},
+    types::{Address, AssetId, Bits256, Identity},
 };
 
 abigen!(Contract(
@@ -67,4 +67,31 @@ pub(crate) async fn setup() -> (
     let instance_2 = PropsRegistry::new(id.clone(), wallet2.clone());
 
     (wallet1, wallet2, id.into(), instance_1, instance_2)
-}
\ No newline at end of file
+}
+
+pub(crate) async fn deploy_target(wallet: &WalletUnlocked, owner: Identity, salt: u8) -> ContractId {
+    // Any SRC-5 contract will do as a registration target, so reuse the registry bytecode with a new salt
+    let id = Contract::load_from(REGISTRY_BINARY_PATH, LoadConfiguration::default().with_salt([salt; 32]))
+        .unwrap()
+        .deploy(wallet, TxPolicies::default())
+        .await
+        .unwrap();
+
+    PropsRegistry::new(id.clone(), wallet.clone())
+        .methods()
+        .constructor(owner)
+        .call()
+        .await
+        .unwrap();
+
+    id.into()
+}
+
+pub(crate) fn template_bytecode() -> Vec<u8> {
+    std::fs::read(REGISTRY_BINARY_PATH).unwrap()
+}
+
+pub(crate) fn template_root() -> Bits256 {
+    let contract = Contract::load_from(REGISTRY_BINARY_PATH, LoadConfiguration::default()).unwrap();
+    Bits256(*contract.code_root())
+}
@@ -1,6 +1,7 @@
 library;
 use std::{string::String};
 use standards::{src5::{State}, src7::{Metadata}};
+use ::fees::{FeeConfig, FeeOverride};
 
 pub struct MintEvent {
     pub recipient: Identity,
@@ -52,3 +53,134 @@ pub struct SetMerkleRootEvent {
 pub struct SetBaseUriEvent {
     pub base_uri: String
 }
+
+pub struct MetadataFrozenEvent {
+    pub sender: Identity
+}
+
+pub struct MaxSupplyChangedEvent {
+    pub previous_max_supply: u64,
+    pub new_max_supply: u64,
+    pub sender: Identity
+}
+
+pub struct SupplyLockedEvent {
+    pub max_supply: u64,
+    pub sender: Identity
+}
+
+pub struct RoleGrantedEvent {
+    pub role: b256,
+    pub account: Identity,
+    pub sender: Identity
+}
+
+pub struct RoleRevokedEvent {
+    pub role: b256,
+    pub account: Identity,
+    pub sender: Identity
+}
+
+pub struct CreateEditionEvent {
+    pub asset: AssetId,
+    pub sub_id: SubId,
+    pub price: u64,
+    pub max_supply: u64,
+    pub start_date: u64,
+    pub end_date: u64
+}
+
+pub struct SetEditionPriceEvent {
+    pub asset: AssetId,
+    pub price: u64
+}
+
+pub struct SetEditionDatesEvent {
+    pub asset: AssetId,
+    pub start: u64,
+    pub end: u64
+}
+
+pub struct EditionMintEvent {
+    pub recipient: Identity,
+    pub asset: AssetId,
+    pub amount: u64,
+    pub affiliate: Identity,
+    pub total_price: u64,
+    pub total_fee: u64,
+    pub price_amount: u64,
+    pub builder_fee: u64,
+    pub affiliate_fee: u64,
+    pub fee: u64,
+    pub creator_price: u64
+}
+
+pub struct EditionAirdropEvent {
+    pub recipient: Identity,
+    pub asset: AssetId,
+    pub amount: u64
+}
+
+pub struct FeeChangeQueuedEvent {
+    pub asset: AssetId,
+    pub config: FeeConfig,
+    pub effective_at: u64
+}
+
+pub struct FeeOverrideChangeQueuedEvent {
+    pub contract_id: Option<ContractId>,
+    pub category: Option<String>,
+    pub fee_override: Option<FeeOverride>,
+    pub effective_at: u64
+}
+
+pub struct RegistryChangeQueuedEvent {
+    pub registry: Option<ContractId>,
+    pub effective_at: u64
+}
+
+pub struct FeeOverrideSetEvent {
+    pub contract_id: Option<ContractId>,
+    pub category: Option<String>,
+    pub fee_override: Option<FeeOverride>
+}
+
+pub struct RegistrySetEvent {
+    pub registry: Option<ContractId>
+}
+
+pub struct DistributionThresholdSetEvent {
+    pub asset: AssetId,
+    pub threshold: u64
+}
+
+pub struct FeeSetEvent {
+    pub asset: AssetId,
+    pub config: FeeConfig
+}
+
+pub struct SharesSetEvent {
+    pub payee: Identity,
+    pub shares: u64,
+    pub total_shares: u64
+}
+
+pub struct FundsReceivedEvent {
+    pub sender: Identity,
+    pub source: Option<ContractId>,
+    pub asset: AssetId,
+    pub amount: u64
+}
+
+pub struct FundsReleasedEvent {
+    pub payee: Identity,
+    pub asset: AssetId,
+    pub amount: u64
+}
+
+pub struct FundsSweptEvent {
+    pub asset: AssetId,
+    pub amount: u64,
+    pub recipient: Identity,
+    pub sender: Identity
+}
@@ -0,0 +1,84 @@
+library;
+
+/// How the protocol fee charged on a mint is computed.
+///
+/// The flat and percentage parts are added together, then clamped to the bounds.
+pub struct FeeConfig {
+    /// The flat fee charged per token.
+    pub flat: u64,
+    /// The share of the creator price charged, in basis points.
+    pub basis_points: u64,
+    /// The minimum fee charged per mint.
+    pub min: u64,
+    /// The maximum fee charged per mint, or zero for no maximum.
+    pub max: u64,
+}
+
+impl FeeConfig {
+    /// Returns the fee for minting `amount` tokens at `price` each.
+    ///
+    /// Minting nothing is free. The fee is computed in `u256` so large prices do not overflow.
+    pub fn quote(self, price: u64, amount: u64) -> u64 {
+        if amount == 0 {
+            return 0;
+        }
+
+        let amount = amount.as_u256();
+        let mut fee = (self.flat.as_u256() * amount) + ((price.as_u256() * amount * self.basis_points.as_u256()) / 10_000);
+        if fee < self.min.as_u256() {
+            fee = self.min.as_u256();
+        }
+        if self.max > 0 && fee > self.max.as_u256() {
+            fee = self.max.as_u256();
+        }
+        <u64 as TryFrom<u256>>::try_from(fee).unwrap()
+    }
+
+    /// Returns whether switching to `other` can raise the fee of any mint.
+    pub fn is_raised_by(self, other: FeeConfig) -> bool {
+        let raises_max = self.max != 0 && (other.max == 0 || other.max > self.max);
+        other.flat > self.flat || other.basis_points > self.basis_points || other.min > self.min || raises_max
+    }
+}
+
+/// A custom fee configuration for a partner contract or category.
+///
+/// A zero configuration waives the fee entirely.
+pub struct FeeOverride {
+    /// The fee configuration that replaces the default one.
+    pub config: FeeConfig,
+    /// The timestamp after which the override no longer applies, or zero if it never expires.
+    pub expires_at: u64,
+}
+
+/// A fee configuration change that is waiting for its timelock to pass.
+pub struct PendingFee {
+    /// The fee configuration that will apply.
+    pub config: FeeConfig,
+    /// The timestamp from which the change can be applied.
+    pub effective_at: u64,
+}
+
+/// A fee override change that is waiting for its timelock to pass.
+pub struct PendingFeeOverride {
+    /// The override that will apply, or `None` if it will be removed.
+    pub fee_override: Option<FeeOverride>,
+    /// The timestamp from which the change can be applied.
+    pub effective_at: u64,
+}
+
+/// A registry change that is waiting for its timelock to pass.
+pub struct PendingRegistry {
+    /// The registry that will be used, or `None` if category overrides will be disabled.
+    pub registry: Option<ContractId>,
+    /// The timestamp from which the change can be applied.
+    pub effective_at: u64,
+}
+
+/// A protocol fee contract change that is waiting for its timelock to pass.
+pub struct PendingFeeContract {
+    /// The fee splitter that will be used, or `None` if contracts will use their built-in one.
+    pub fee_contract: Option<ContractId>,
+    /// The timestamp from which the change can be applied.
+    pub effective_at: u64,
+}
@@ -1,6 +1,10 @@
 library;
 
 pub mod events;
+pub mod fees;
+pub mod ownership;
+pub mod roles;
+pub mod sweep;
 
 pub use events::{
     MintEvent,
@@ -10,12 +14,93 @@ pub use events::{
     SetMintPriceEvent,
     SetMintDatesEvent,
     SetMerkleRootEvent,
-    SetBaseUriEvent
+    SetBaseUriEvent,
+    MetadataFrozenEvent,
+    MaxSupplyChangedEvent,
+    SupplyLockedEvent,
+    RoleGrantedEvent,
+    RoleRevokedEvent,
+    CreateEditionEvent,
+    SetEditionPriceEvent,
+    SetEditionDatesEvent,
+    EditionMintEvent,
+    EditionAirdropEvent,
+    FeeChangeQueuedEvent,
+    FeeOverrideChangeQueuedEvent,
+    RegistryChangeQueuedEvent,
+    FeeOverrideSetEvent,
+    RegistrySetEvent,
+    DistributionThresholdSetEvent,
+    FeeSetEvent,
+    SharesSetEvent,
+    FundsReceivedEvent,
+    FundsReleasedEvent,
+    FundsSweptEvent
 };
+pub use fees::{FeeConfig, FeeOverride, PendingFee, PendingFeeContract, PendingFeeOverride, PendingRegistry};
 
 use std::string::String;
 use std::bytes::Bytes;
-use standards::{src5::{State}, src7::{Metadata}};
+use standards::{src5::{State}, src7::{Metadata}, src12::{ContractConfigurables}};
+
+/// The kinds of Props contracts the registry can initialize and track.
+pub enum ContractType {
+    Edition: (),
+    Collection: (),
+    MultiEdition: (),
+}
+
+impl core::ops::Eq for ContractType {
+    fn eq(self, other: Self) -> bool {
+        match (self, other) {
+            (ContractType::Edition, ContractType::Edition) => true,
+            (ContractType::Collection, ContractType::Collection) => true,
+            (ContractType::MultiEdition, ContractType::MultiEdition) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Proof that a contract was deployed from a Props template for a given owner.
+///
+/// The deployment salt must be `sha256((owner.bits(), salt))`, which ties the deployment to its
+/// owner. Only the owner can submit the proof, so nobody else can initialize it.
+pub struct Deployment {
+    /// The template bytecode the contract was deployed from.
+    pub bytecode: Vec<u8>,
+    /// The configurables applied at deployment, if any.
+    pub configurables: Option<ContractConfigurables>,
+    /// The value the deployment salt was derived from.
+    pub salt: b256,
+    /// The root of the contract's initial storage slots.
+    pub state_root: b256,
+}
+
+/// Protocol-wide settings served by the registry to the contracts it deployed.
+pub struct ProtocolConfig {
+    /// The fee splitter that overrides the contract's built-in fee contract, if any.
+    pub fee_contract: Option<ContractId>,
+    /// The affiliate fee percentage used when a contract does not configure its own.
+    pub affiliate_fee_percentage: u64,
+    /// Whether minting is paused across all contracts that read this configuration.
+    pub paused: bool,
+}
+
+/// The breakdown of what a mint charges, as returned by `quote_mint`.
+pub struct MintQuote {
+    /// The part of `total` that goes to the contract owner.
+    pub creator_price: u64,
+    /// The fee sent to the fee splitter.
+    pub protocol_fee: u64,
+    /// The fixed fee sent to the builder.
+    pub builder_fee: u64,
+    /// The builder's share of the creator price.
+    pub builder_revenue_share: u64,
+    /// The affiliate's share of the creator price.
+    pub affiliate_fee: u64,
+    /// The exact amount of the base asset to forward to `mint`.
+    pub total: u64,
+}
 
 abi Props721Collection {
     #[storage(read, write)]
@@ -27,9 +112,14 @@ abi Props721Edition {
     fn constructor(owner: Identity, name: String, symbol: String, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, start_date: u64, end_date: u64);
 }
 
+abi Props1155Edition {
+    #[storage(read, write)]
+    fn constructor(owner: Identity, name: String, symbol: String);
+}
+
 abi PropsRegistry {
     #[storage(read, write)]
-    fn register(contractId: ContractId, owner: Identity);
+    fn register(contractId: ContractId, owner: Identity, bytecode: Vec<u8>, configurables: Option<ContractConfigurables>);
 
     #[storage(read, write)]
     fn deregister(contractId: ContractId);
@@ -38,10 +128,100 @@ abi PropsRegistry {
     fn constructor(owner: Identity);
 
     #[storage(read, write)]
-    fn init_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, start_date: u64, end_date: u64);
+    fn init_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, metadata_keys: Vec<String>, metadata_values: Vec<Metadata>, price: u64, start_date: u64, end_date: u64, deployment: Deployment);
+
+    #[storage(read, write)]
+    fn init_collection(contract_id: ContractId, owner: Identity, name: String, symbol: String, baseUri: String, price: u64, startDate: u64, endDate: u64, deployment: Deployment);
+
+    #[storage(read, write)]
+    fn init_multi_edition(contract_id: ContractId, owner: Identity, name: String, symbol: String, deployment: Deployment);
+
+    #[storage(read, write)]
+    fn add_template(bytecode_root: b256, contract_type: ContractType, configurable_ranges: Vec<(u64, u64)>, registry_offset: Option<u64>);
+
+    #[storage(read, write)]
+    fn remove_template(bytecode_root: b256);
+
+    #[storage(read)]
+    fn is_template(bytecode_root: b256) -> bool;
+
+    #[storage(read)]
+    fn is_registered(contract_id: ContractId) -> bool;
+
+    #[storage(read)]
+    fn owner_of(contract_id: ContractId) -> Option<Identity>;
+
+    #[storage(read)]
+    fn contract_type(contract_id: ContractId) -> Option<ContractType>;
+
+    #[storage(read)]
+    fn contracts(offset: u64, limit: u64) -> Vec<ContractId>;
+
+    #[storage(read)]
+    fn contracts_by_owner(owner: Identity, offset: u64, limit: u64) -> Vec<ContractId>;
+
+    #[storage(read)]
+    fn count() -> u64;
+
+    #[storage(read, write)]
+    fn set_curator(curator: Identity, enabled: bool);
+
+    #[storage(read)]
+    fn is_curator(identity: Identity) -> bool;
+
+    #[storage(read, write)]
+    fn set_verified(contract_id: ContractId, verified: bool);
+
+    #[storage(read)]
+    fn is_verified(contract_id: ContractId) -> bool;
+
+    #[storage(read)]
+    fn verified_contracts(offset: u64, limit: u64) -> Vec<ContractId>;
+
+    #[storage(read, write)]
+    fn set_category(contract_id: ContractId, category: Option<String>);
+
+    #[storage(read)]
+    fn category(contract_id: ContractId) -> Option<String>;
+
+    #[storage(read)]
+    fn contracts_by_category(category: String, offset: u64, limit: u64) -> Vec<ContractId>;
+
+    #[storage(read, write)]
+    fn set_tags(contract_id: ContractId, tags: Vec<String>);
+
+    #[storage(read)]
+    fn tags(contract_id: ContractId) -> Vec<String>;
+
+    #[storage(read, write)]
+    fn migrate(old_contract_id: ContractId, new_contract_id: ContractId);
+
+    #[storage(read)]
+    fn migrated_to(old_contract_id: ContractId) -> Option<ContractId>;
+
+    #[storage(read, write)]
+    fn deprecate(contract_id: ContractId);
+
+    #[storage(read)]
+    fn is_deprecated(contract_id: ContractId) -> bool;
+
+    #[storage(read, write)]
+    fn set_fee_contract(fee_contract: Option<ContractId>);
+
+    #[storage(read)]
+    fn pending_fee_contract() -> Option<PendingFeeContract>;
 
     #[storage(read, write)]
-    fn init_collection(contract_id: ContractId, owner: Identity, name: String, symbol: String, baseUri: String, price: u64, startDate: u64, endDate: u64);
+    fn apply_fee_contract();
+
+    #[storage(read, write)]
+    fn set_default_affiliate_fee_percentage(percentage: u64);
+
+    #[storage(read, write)]
+    fn set_protocol_paused(paused: bool);
+
+    #[storage(read)]
+    fn protocol_config() -> ProtocolConfig;
 }
 
 abi PropsFeeSplitter {
@@ -51,20 +231,129 @@ abi PropsFeeSplitter {
     #[storage(read)]
     fn fee() -> Option<u64>;
 
-    #[storage(write)]
+    #[storage(read, write)]
+    fn set_fee_for(asset: AssetId, amount: u64);
+
+    #[storage(read)]
+    fn fee_for(asset: AssetId) -> Option<u64>;
+
+    #[storage(read, write)]
+    fn set_fee_config(asset: AssetId, config: FeeConfig);
+
+    #[storage(read)]
+    fn fee_config(asset: AssetId) -> FeeConfig;
+
+    #[storage(read)]
+    fn pending_fee(asset: AssetId) -> Option<PendingFee>;
+
+    #[storage(read, write)]
+    fn apply_fee(asset: AssetId);
+
+    #[storage(read)]
+    fn quote_fee(price: u64, amount: u64) -> u64;
+
+    #[storage(read, write)]
+    fn set_fee_override(contract_id: ContractId, fee_override: Option<FeeOverride>);
+
+    #[storage(read)]
+    fn fee_override(contract_id: ContractId) -> Option<FeeOverride>;
+
+    #[storage(read)]
+    fn pending_fee_override(contract_id: ContractId) -> Option<PendingFeeOverride>;
+
+    #[storage(read, write)]
+    fn apply_fee_override(contract_id: ContractId);
+
+    #[storage(read, write)]
+    fn set_category_fee_override(category: String, fee_override: Option<FeeOverride>);
+
+    #[storage(read)]
+    fn category_fee_override(category: String) -> Option<FeeOverride>;
+
+    #[storage(read)]
+    fn pending_category_fee_override(category: String) -> Option<PendingFeeOverride>;
+
+    #[storage(read, write)]
+    fn apply_category_fee_override(category: String);
+
+    #[storage(read, write)]
+    fn set_registry(registry: Option<ContractId>);
+
+    #[storage(read)]
+    fn registry() -> Option<ContractId>;
+
+    #[storage(read)]
+    fn pending_registry() -> Option<PendingRegistry>;
+
+    #[storage(read, write)]
+    fn apply_registry();
+
+    #[storage(read)]
+    fn quote_fee_for(contract_id: ContractId, price: u64, amount: u64) -> u64;
+
+    #[storage(read, write)]
     fn set_shares(recipients: Vec<Identity>, shares: Vec<u64>);
 
+    #[storage(read, write)]
+    fn add_payee(payee: Identity, share: u64);
+
+    #[storage(read, write)]
+    fn update_share(payee: Identity, share: u64);
+
+    #[storage(read, write)]
+    fn remove_payee(payee: Identity);
+
+    #[storage(read)]
+    fn payees() -> Vec<(Identity, u64)>;
+
     #[storage(read)]
     fn get_share() -> Option<u64>;
 
     #[storage(read)]
     fn total_shares() -> Option<u64>;
 
-    #[storage(write), payable]
+    #[storage(read, write), payable]
     fn receive_funds();
 
+    #[storage(read)]
+    fn releasable(payee: Identity, asset: AssetId) -> u64;
+
+    #[storage(read)]
+    fn released(payee: Identity, asset: AssetId) -> u64;
+
     #[storage(read, write)]
-    fn distribute_funds(amount: u64);
+    fn release(payee: Identity, asset: AssetId);
+
+    #[storage(read, write)]
+    fn set_distribution_threshold(asset: AssetId, threshold: u64);
+
+    #[storage(read)]
+    fn distribution_threshold(asset: AssetId) -> Option<u64>;
+}
+
+abi Ownable {
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity);
+
+    #[storage(read, write)]
+    fn accept_ownership();
+
+    #[storage(read, write)]
+    fn renounce_ownership();
+
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity>;
+}
+
+abi AccessControl {
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity);
+
+    #[storage(read, write)]
+    fn revoke_role(role: b256, account: Identity);
+
+    #[storage(read)]
+    fn has_role(role: b256, account: Identity) -> bool;
 }
 
 abi SRC3PayableExtension {
@@ -90,15 +379,19 @@ abi SetMintMetadata {
     #[storage(read)]
     fn total_price() -> Option<u64>;
 
+    #[storage(read)]
     fn fees() -> Option<(u64, u64)>;
 
+    #[storage(read)]
+    fn quote_mint(recipient: Identity, amount: u64, affiliate: Option<Identity>) -> MintQuote;
+
     #[storage(read)]
     fn start_date() -> Option<u64>;
 
     #[storage(read)]
     fn end_date() -> Option<u64>;
 
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_dates(start: u64, end: u64);
 
     #[storage(write)]
@@ -113,9 +406,99 @@ abi SetMintMetadata {
     #[storage(write)]
     fn set_merkle(root: b256, uri: String);
 
+    #[storage(read)]
     fn max_supply() -> Option<u64>;
 }
 
+abi SetTokenAttributes {
+    #[storage(read, write)]
+    fn set_attributes(asset: AssetId, keys: Vec<String>, values: Vec<Metadata>);
+
+    #[storage(read)]
+    fn attributes(asset: AssetId, offset: u64, limit: u64) -> Vec<(String, Metadata)>;
+}
+
+abi FreezeMetadata {
+    #[storage(read, write)]
+    fn freeze_metadata();
+
+    #[storage(read)]
+    fn metadata_frozen() -> bool;
+}
+
+abi MintLimits {
+    fn max_per_transaction() -> Option<u64>;
+
+    fn mint_cooldown() -> Option<u64>;
+
+    fn mint_cooldown_blocks() -> Option<u32>;
+
+    fn max_mints_per_block() -> Option<u64>;
+
+    #[storage(read)]
+    fn last_mint_time(wallet: Identity) -> Option<u64>;
+
+    #[storage(read)]
+    fn last_mint_height(wallet: Identity) -> Option<u32>;
+}
+
+abi Sweep {
+    #[storage(read)]
+    fn sweep(asset: AssetId, amount: u64, recipient: Identity);
+
+    #[storage(read)]
+    fn sweepable(asset: AssetId) -> u64;
+}
+
+abi SupplyControl {
+    #[storage(read, write)]
+    fn reduce_max_supply(new_max_supply: u64);
+
+    #[storage(read, write)]
+    fn lock_supply();
+
+    #[storage(read)]
+    fn supply_locked() -> bool;
+}
+
+/// Returns the protocol configuration served by `registry`, or the defaults when `registry` is zero.
+pub fn protocol_config(registry: ContractId) -> ProtocolConfig {
+    if registry == ContractId::zero() {
+        return ProtocolConfig {
+            fee_contract: None,
+            affiliate_fee_percentage: 0,
+            paused: false,
+        };
+    }
+    abi(PropsRegistry, registry.bits()).protocol_config()
+}
+
+/// Returns the fee contract to pay, preferring the one set in `config` over `default`.
+pub fn fee_contract_id(config: ProtocolConfig, default: b256) -> b256 {
+    match config.fee_contract {
+        Some(fee_contract) => fee_contract.bits(),
+        None => default,
+    }
+}
+
+/// Returns `percentage`, falling back to the protocol default when it is zero.
+///
+/// The result is capped at what `builder_percentage` leaves of the price, so a protocol default
+/// raised later can never make the shares exceed the price.
+pub fn affiliate_fee_percentage(config: ProtocolConfig, percentage: u64, builder_percentage: u64) -> u64 {
+    let percentage = if percentage > 0 {
+        percentage
+    } else {
+        config.affiliate_fee_percentage
+    };
+    let available = 100 - builder_percentage;
+    if percentage > available {
+        available
+    } else {
+        percentage
+    }
+}
+
 pub fn concat(a: String, b: String) -> String {
     let mut a = a.as_bytes();
     let b = b.as_bytes();
@@ -146,4 +529,26 @@ pub fn convert_num_to_ascii_bytes(num: u64) -> Bytes {
         reversed_bytes.push(bytes.pop().unwrap());
     }
     return reversed_bytes;
-}
\ No newline at end of file
+}
+
+/// Returns the SRC-7 metadata key under which the attribute `name` is exposed, i.e. `attr:<name>`.
+pub fn attribute_key(name: String) -> String {
+    concat(String::from_ascii_str("attr:"), name)
+}
+
+/// Returns the attribute name for an SRC-7 metadata key of the form `attr:<name>`, or `None`
+/// if `key` does not refer to an attribute.
+pub fn attribute_name(key: String) -> Option<String> {
+    let key_bytes = key.as_bytes();
+    let prefix_bytes = String::from_ascii_str("attr:").as_bytes();
+    if key_bytes.len() <= prefix_bytes.len() {
+        return None;
+    }
+
+    let (head, tail) = key_bytes.split_at(prefix_bytes.len());
+    if head != prefix_bytes {
+        return None;
+    }
+
+    Some(String::from_ascii(tail))
+}
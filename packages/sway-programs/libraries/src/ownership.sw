@@ -0,0 +1,66 @@
+library;
+
+use std::{auth::msg_sender, hash::{Hash, sha256}};
+use standards::src5::State;
+use sway_libs::ownership::{_owner, events::OwnershipTransferred, only_owner, renounce_ownership};
+
+pub enum OwnershipError {
+    NotPendingOwner: (),
+    NotRegistry: (),
+}
+
+// The slot `sway_libs::ownership` keeps the SRC-5 ownership state in, so `_owner`, `only_owner`
+// and `initialize_ownership` keep working on the owner set by `_accept_ownership`.
+fn owner_key() -> StorageKey<State> {
+    let slot = sha256("owner");
+    StorageKey::new(slot, 0, slot)
+}
+
+/// Reverts with `OwnershipError::NotRegistry` unless the caller is `registry`.
+///
+/// A zero `registry` fails closed, so a contract deployed without a registry can never be initialized.
+pub fn only_registry(registry: ContractId) {
+    require(
+        registry != ContractId::zero() && msg_sender().unwrap() == Identity::ContractId(registry),
+        OwnershipError::NotRegistry,
+    );
+}
+
+/// Proposes `new_owner` as owner, storing it in `pending_owner`. Ownership only changes once `new_owner` accepts.
+#[storage(read, write)]
+pub fn _transfer_ownership(new_owner: Identity, pending_owner: StorageKey<Option<Identity>>) {
+    only_owner();
+
+    pending_owner.write(Some(new_owner));
+}
+
+/// Makes the identity stored in `pending_owner` the owner of the contract.
+#[storage(read, write)]
+pub fn _accept_ownership(pending_owner: StorageKey<Option<Identity>>) {
+    let sender = msg_sender().unwrap();
+    require(
+        pending_owner.try_read().unwrap_or(None) == Some(sender),
+        OwnershipError::NotPendingOwner,
+    );
+
+    let previous_owner = match _owner() {
+        State::Initialized(owner) => owner,
+        _ => revert(0),
+    };
+
+    owner_key().write(State::Initialized(sender));
+    pending_owner.write(None);
+
+    log(OwnershipTransferred {
+        new_owner: sender,
+        previous_owner,
+    });
+}
+
+/// Permanently revokes ownership of the contract and drops the transfer pending in `pending_owner`.
+#[storage(read, write)]
+pub fn _renounce_ownership(pending_owner: StorageKey<Option<Identity>>) {
+    renounce_ownership();
+
+    pending_owner.write(None);
+}
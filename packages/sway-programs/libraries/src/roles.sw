@@ -0,0 +1,67 @@
+library;
+
+use std::{auth::msg_sender, hash::{Hash, sha256}};
+use standards::src5::State;
+use sway_libs::{admin::{add_admin, is_admin, remove_admin}, ownership::_owner};
+use ::events::{RoleGrantedEvent, RoleRevokedEvent};
+
+pub enum RoleError {
+    MissingRole: b256,
+}
+
+/// May airdrop and manage the allowlist. sha256("props.role.minter")
+pub const MINTER_ROLE: b256 = 0xc0afe8842d32f1ee3f8f85991c829090549ec013f89299dbd8390d0ab0c0be2c;
+
+/// May edit the name, symbol, mint dates, metadata and attributes. sha256("props.role.metadata")
+pub const METADATA_ROLE: b256 = 0xf669872862df7555b9b1939f1c370c21528adad9f33348cb33a9eb1040f31d9f;
+
+/// May pause and unpause the contract. sha256("props.role.pauser")
+pub const PAUSER_ROLE: b256 = 0xae7ed0893c4780ac815ab77e870c44a80c46e929efe2af7bcefdd6bd4f8560d3;
+
+/// May change the price and handle funds. sha256("props.role.finance")
+pub const FINANCE_ROLE: b256 = 0x49485ec67416a1b84a893d97ca728a6b48bdfece15fe2dff666b649150d4183d;
+
+/// Returns the `sway_libs::admin` entry that stands for `account` holding `role`.
+fn role_admin(role: b256, account: Identity) -> Identity {
+    Identity::Address(Address::from(sha256((role, account))))
+}
+
+/// Returns whether `account` has been granted `role`.
+#[storage(read)]
+pub fn _has_role(role: b256, account: Identity) -> bool {
+    is_admin(role_admin(role, account))
+}
+
+/// Reverts with `RoleError::MissingRole` unless the caller is the owner or has been granted `role`.
+#[storage(read)]
+pub fn only_role(role: b256) {
+    let sender = msg_sender().unwrap();
+    require(
+        _owner() == State::Initialized(sender) || _has_role(role, sender),
+        RoleError::MissingRole(role),
+    );
+}
+
+/// Grants `role` to `account`. Only callable by the owner.
+#[storage(read, write)]
+pub fn _grant_role(role: b256, account: Identity) {
+    add_admin(role_admin(role, account));
+
+    log(RoleGrantedEvent {
+        role,
+        account,
+        sender: msg_sender().unwrap(),
+    });
+}
+
+/// Revokes `role` from `account`. Only callable by the owner.
+#[storage(read, write)]
+pub fn _revoke_role(role: b256, account: Identity) {
+    remove_admin(role_admin(role, account));
+
+    log(RoleRevokedEvent {
+        role,
+        account,
+        sender: msg_sender().unwrap(),
+    });
+}
@@ -0,0 +1,30 @@
+library;
+
+use std::{asset::transfer, auth::msg_sender};
+use sway_libs::ownership::only_owner;
+use ::events::FundsSweptEvent;
+
+pub enum SweepError {
+    AmountIsZero: (),
+    ExceedsSweepableBalance: u64,
+}
+
+/// Transfers `amount` of `asset` held by the contract to `recipient`.
+///
+/// Only the owner may sweep, and never more than `sweepable`, the part of the balance that is not owed
+/// to anyone.
+#[storage(read)]
+pub fn _sweep(asset: AssetId, amount: u64, recipient: Identity, sweepable: u64) {
+    only_owner();
+    require(amount > 0, SweepError::AmountIsZero);
+    require(amount <= sweepable, SweepError::ExceedsSweepableBalance(sweepable));
+
+    transfer(recipient, asset, amount);
+
+    log(FundsSweptEvent {
+        asset,
+        amount,
+        recipient,
+        sender: msg_sender().unwrap(),
+    });
+}